@@ -18,14 +18,28 @@ pub struct CameraPlugin;
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((fly_camera::plugin, orbit_camera::plugin));
-        app.add_systems(Update, (view_input, update_origin).chain());
+        app.add_systems(
+            Update,
+            ((view_input, update_origin).chain(), update_sprint_fov),
+        );
+        // Platforms turn the character once per fixed tick, after character movement
+        app.add_systems(FixedPostUpdate, follow_platform_yaw);
         app.add_observer(toggle_cam_perspective);
         app.add_observer(toggle_fly_cam);
     }
 }
 
 #[derive(Component)]
-#[require(Camera3d, Sensitivity, ViewAngles, FollowOrigin, SpringArm, FlySpeed)]
+#[require(
+    Camera3d,
+    Sensitivity,
+    ViewAngles,
+    FollowOrigin,
+    SpringArm,
+    FlySpeed,
+    SprintFov,
+    FollowPlatformYaw
+)]
 pub struct MainCamera;
 
 /// The look sensitivity of a camera
@@ -53,6 +67,48 @@ impl ViewAngles {
     }
 }
 
+/// Widens the field of view of a camera while the entity it's attached to is sprinting.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub(crate) struct SprintFov {
+    /// Field of view added while sprinting, in radians.
+    pub boost: f32,
+    /// How fast the field of view blends in and out.
+    pub blend_speed: f32,
+    pub active: bool,
+    /// The boost currently applied to the camera's projection.
+    pub current: f32,
+}
+
+impl Default for SprintFov {
+    fn default() -> Self {
+        Self {
+            boost: 10_f32.to_radians(),
+            blend_speed: 8.0,
+            active: false,
+            current: 0.0,
+        }
+    }
+}
+
+/// Turns a camera along with the platform the entity it's attached to is standing on.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct FollowPlatformYaw(pub bool);
+
+impl Default for FollowPlatformYaw {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// Yaw the entity has been turned by the platform it stands on during the last fixed tick, in radians.
+///
+/// Attached cameras with [`FollowPlatformYaw`] turn along with it.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct PlatformYaw(pub f32);
+
 /// The origin of an attached camera, corresponds to the translation of the [`AttachedTo`] entity + [`FollowOffset`]
 #[derive(Component, Reflect, Default, Debug, PartialEq, Clone, Copy)]
 #[reflect(Component)]
@@ -115,6 +171,44 @@ fn toggle_fly_cam(
     Ok(())
 }
 
+fn update_sprint_fov(mut cameras: Query<(&mut SprintFov, &mut Projection)>, time: Res<Time>) {
+    for (mut fov, mut projection) in &mut cameras {
+        let Projection::Perspective(perspective) = projection.as_mut() else {
+            continue;
+        };
+
+        let target = match fov.active {
+            true => fov.boost,
+            false => 0.0,
+        };
+
+        // Only apply the change in boost so the base field of view is left untouched
+        let boost = fov
+            .current
+            .lerp(target, fov.blend_speed * time.delta_secs());
+        perspective.fov += boost - fov.current;
+        fov.current = boost;
+    }
+}
+
+fn follow_platform_yaw(
+    targets: Query<(&PlatformYaw, &Attachments)>,
+    mut cameras: Query<(&mut ViewAngles, &FollowPlatformYaw)>,
+) {
+    for (platform_yaw, attachments) in &targets {
+        if platform_yaw.0 == 0.0 {
+            continue;
+        }
+
+        let mut iter = cameras.iter_many_mut(attachments.iter());
+        while let Some((mut angles, follow)) = iter.fetch_next() {
+            if follow.0 {
+                angles.yaw += platform_yaw.0;
+            }
+        }
+    }
+}
+
 pub(crate) fn view_input(
     mut cameras: Query<(&mut ViewAngles, &mut Transform, &Sensitivity)>,
     actions: Single<&Actions<DefaultContext>>,
@@ -2,6 +2,7 @@ use super::{Attachments, FollowOrigin};
 use crate::{
     AttachedTo,
     input::{OrbitCameraContext, OrbitZoom},
+    layers::GameLayer,
 };
 use avian3d::prelude::*;
 use bevy::prelude::*;
@@ -33,7 +34,7 @@ impl Default for SpringArm {
             target_distance: 4.0,
             recover_speed: 6.0,
             collision_radius: 0.1,
-            filters: LayerMask::ALL,
+            filters: GameLayer::CAMERA_BLOCKERS.into(),
         }
     }
 }
@@ -16,6 +16,9 @@ pub const EXAMPLE_JUMP_IMPULSE: f32 = 6.0;
 pub const EXAMPLE_GRAVITY: f32 = 20.0; // realistic earth gravity tend to feel wrong for games
 pub const EXAMPLE_STEP_HEIGHT: f32 = 0.25;
 pub const EXAMPLE_GROUND_CHECK_DISTANCE: f32 = 0.1;
+pub const EXAMPLE_STEP_DOWN_HEIGHT: f32 = 0.35;
+pub const EXAMPLE_MAX_SNAP_SPEED: f32 = 15.0;
+pub const EXAMPLE_PERCH_RADIUS: f32 = 0.15;
 
 // @todo: probably want to improve the ergonomics of these
 // functions by accepting a struct instead of a bunch of arguments,
@@ -49,6 +52,326 @@ impl Ground {
     pub fn is_walkable(&self, up: Dir3, walkable_angle: f32) -> bool {
         is_walkable(*self.normal, up, walkable_angle)
     }
+
+    /// Returns the [`SurfaceMaterial`] of the ground entity, or the default material if it doesn't have one.
+    pub fn material(&self, materials: &Query<&SurfaceMaterial>) -> SurfaceMaterial {
+        materials.get(self.entity).copied().unwrap_or_default()
+    }
+
+    /// Returns the world-space [`SurfaceVelocity`] of the ground entity, or zero if it doesn't have one.
+    pub fn surface_velocity(&self, surfaces: &Query<(&SurfaceVelocity, &GlobalTransform)>) -> Vec3 {
+        surfaces
+            .get(self.entity)
+            .map_or(Vec3::ZERO, |(velocity, transform)| {
+                transform.rotation() * velocity.0
+            })
+    }
+}
+
+/// Modifies how characters move while standing on a collider.
+///
+/// Multipliers are relative to the character's regular ground movement.
+#[derive(Component, Reflect, Debug, PartialEq, Clone, Copy)]
+#[reflect(Component)]
+pub struct SurfaceMaterial {
+    pub friction: f32,
+    pub acceleration: f32,
+    pub max_speed: f32,
+    /// Fraction of gravity pulling the character down walkable slopes, `0.0` means the character stands still.
+    pub slipperiness: f32,
+}
+
+impl SurfaceMaterial {
+    pub const DEFAULT: Self = Self {
+        friction: 1.0,
+        acceleration: 1.0,
+        max_speed: 1.0,
+        slipperiness: 0.0,
+    };
+
+    pub const ICE: Self = Self {
+        friction: 0.05,
+        acceleration: 0.15,
+        max_speed: 1.0,
+        slipperiness: 0.8,
+    };
+
+    pub const MUD: Self = Self {
+        friction: 2.0,
+        acceleration: 0.6,
+        max_speed: 0.5,
+        slipperiness: 0.0,
+    };
+}
+
+impl Default for SurfaceMaterial {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Gives the surface of a collider a velocity without moving the collider, like a conveyor belt.
+///
+/// The velocity is in the local space of the collider.
+#[derive(Component, Reflect, Default, Debug, PartialEq, Clone, Copy)]
+#[reflect(Component)]
+pub struct SurfaceVelocity(pub Vec3);
+
+/// How far a grounded character is pulled down to stay on the ground when walking down steps or over crests.
+#[derive(Reflect, Debug, PartialEq, Clone, Copy)]
+pub struct GroundSnapping {
+    /// Maximum distance to snap down to the ground below.
+    pub step_down_height: f32,
+    /// The character leaves the ground instead of snapping when moving faster than this.
+    pub max_snap_speed: f32,
+    /// Maximum angle between the current and the new ground normal, in radians.
+    ///
+    /// Crests sharper than this launch the character instead.
+    pub max_slope_change: f32,
+    /// Ground normals closer than this are considered the same slope, in radians.
+    pub same_slope_tolerance: f32,
+    /// Ground closer than this is still in contact with the character and isn't snapped to.
+    pub contact_distance: f32,
+}
+
+impl GroundSnapping {
+    /// Returns `true` if a character moving at `velocity` can snap from `ground` to the `target` ground
+    /// `distance` below it.
+    pub fn can_snap(
+        &self,
+        velocity: Vec3,
+        distance: f32,
+        ground: &Ground,
+        target: &Ground,
+    ) -> bool {
+        distance <= self.step_down_height
+            && velocity.length() <= self.max_snap_speed
+            && ground.normal.angle_between(*target.normal) <= self.max_slope_change
+    }
+
+    /// Returns `true` if a character moving at `velocity` keeps standing on the `target` ground `distance`
+    /// below its current `ground`.
+    ///
+    /// Small drops within `ground_check_distance` onto the same slope are kept below the maximum snap speed,
+    /// anything else has to pass [`Self::can_snap`] so crests and ramp lips can launch the character.
+    pub fn keeps_ground(
+        &self,
+        velocity: Vec3,
+        distance: f32,
+        ground_check_distance: f32,
+        ground: &Ground,
+        target: &Ground,
+    ) -> bool {
+        let same_slope = ground.normal.angle_between(*target.normal) <= self.same_slope_tolerance;
+
+        // Still touching the same slope, there's nothing to snap
+        if same_slope && distance <= self.contact_distance {
+            return true;
+        }
+
+        if same_slope
+            && distance <= ground_check_distance
+            && velocity.length() <= self.max_snap_speed
+        {
+            return true;
+        }
+
+        self.can_snap(velocity, distance, ground, target)
+    }
+}
+
+impl Default for GroundSnapping {
+    fn default() -> Self {
+        Self {
+            step_down_height: EXAMPLE_STEP_DOWN_HEIGHT,
+            max_snap_speed: EXAMPLE_MAX_SNAP_SPEED,
+            max_slope_change: EXAMPLE_WALKABLE_ANGLE,
+            same_slope_tolerance: 0.01,
+            contact_distance: 0.001,
+        }
+    }
+}
+
+/// How characters stand on the edges of ledges, beams and ridges.
+#[derive(Reflect, Debug, PartialEq, Clone, Copy)]
+pub struct EdgeHandling {
+    /// Maximum horizontal distance from the center of the character to an edge it stands on,
+    /// the character slides off of edges further out.
+    pub perch_radius: f32,
+    /// Stop crouched characters from walking off of ledges.
+    pub protect_ledges_while_crouched: bool,
+}
+
+impl Default for EdgeHandling {
+    fn default() -> Self {
+        Self {
+            perch_radius: EXAMPLE_PERCH_RADIUS,
+            protect_ledges_while_crouched: true,
+        }
+    }
+}
+
+/// Which speed is kept when walking on slopes.
+#[derive(Reflect, Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum SlopeSpeedMode {
+    /// The speed along the ground plane is faster than the target speed on slopes.
+    #[default]
+    Horizontal,
+    /// The speed along the ground plane matches the target speed, so the character covers less ground on slopes.
+    Full,
+}
+
+/// Modifies the target speed of a character walking on slopes.
+///
+/// The curves map the slope angle along the movement direction, in radians, to a speed multiplier.
+#[derive(Reflect, Debug, Clone)]
+pub struct SlopeSpeed {
+    pub uphill: UnevenSampleAutoCurve<f32>,
+    pub downhill: UnevenSampleAutoCurve<f32>,
+    pub mode: SlopeSpeedMode,
+}
+
+impl SlopeSpeed {
+    /// Returns the speed multiplier when moving in `direction` on ground with the given `normal`.
+    pub fn multiplier(&self, direction: Vec3, normal: Dir3, up: Dir3) -> f32 {
+        let Ok(along) = Dir3::new(project_motion_on_ground(direction, normal, up)) else {
+            return 1.0;
+        };
+
+        // Positive when moving uphill
+        let angle = up.dot(*along).clamp(-1.0, 1.0).asin();
+
+        let multiplier = match angle >= 0.0 {
+            true => self.uphill.sample_clamped(angle),
+            false => self.downhill.sample_clamped(-angle),
+        };
+
+        match self.mode {
+            SlopeSpeedMode::Horizontal => multiplier,
+            SlopeSpeedMode::Full => multiplier * angle.cos(),
+        }
+    }
+}
+
+impl Default for SlopeSpeed {
+    fn default() -> Self {
+        Self {
+            uphill: UnevenSampleAutoCurve::new([(0.0, 1.0), (EXAMPLE_WALKABLE_ANGLE, 0.6)])
+                .expect("slope speed curve samples should be valid"),
+            downhill: UnevenSampleAutoCurve::new([(0.0, 1.0), (EXAMPLE_WALKABLE_ANGLE, 1.15)])
+                .expect("slope speed curve samples should be valid"),
+            mode: SlopeSpeedMode::Horizontal,
+        }
+    }
+}
+
+/// Which axes of the ground velocity a character keeps when it leaves a moving platform.
+#[derive(Reflect, Debug, PartialEq, Clone, Copy)]
+pub struct PlatformInheritance {
+    /// Keep the velocity perpendicular to the `up` direction.
+    pub horizontal: bool,
+    /// Keep the velocity along the `up` direction.
+    pub vertical: bool,
+}
+
+impl PlatformInheritance {
+    pub const ALL: Self = Self {
+        horizontal: true,
+        vertical: true,
+    };
+
+    pub const HORIZONTAL: Self = Self {
+        horizontal: true,
+        vertical: false,
+    };
+
+    pub const VERTICAL: Self = Self {
+        horizontal: false,
+        vertical: true,
+    };
+
+    pub const NONE: Self = Self {
+        horizontal: false,
+        vertical: false,
+    };
+
+    /// Returns the part of the platform `velocity` that should be kept.
+    pub fn apply(&self, velocity: Vec3, up: Dir3) -> Vec3 {
+        let vertical = velocity.project_onto_normalized(*up);
+        let horizontal = velocity - vertical;
+
+        match (self.horizontal, self.vertical) {
+            (true, true) => velocity,
+            (true, false) => horizontal,
+            (false, true) => vertical,
+            (false, false) => Vec3::ZERO,
+        }
+    }
+}
+
+impl Default for PlatformInheritance {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// Makes a collider solid only from above, characters pass through it from below and from the side.
+///
+/// The top face points along the local `Y` axis of the collider.
+/// Characters standing on it can drop through by holding back and jumping.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct OneWayPlatform;
+
+/// Returns `true` if a character moving with `velocity` collides with a one-way platform facing `top`
+/// when hitting it with the surface `normal`.
+pub fn one_way_blocks(top: Dir3, normal: Vec3, velocity: Vec3) -> bool {
+    // Only the top face blocks, and only when moving down onto it
+    is_walkable(normal, top, EXAMPLE_WALKABLE_ANGLE) && velocity.dot(*top) <= 0.0
+}
+
+/// Returns `true` if `entity` is a [`OneWayPlatform`] that a character moving with `velocity` passes through
+/// when hitting it with the surface `normal`.
+pub fn passes_one_way(
+    one_way_platforms: &Query<&GlobalTransform, With<OneWayPlatform>>,
+    entity: Entity,
+    normal: Vec3,
+    velocity: Vec3,
+) -> bool {
+    one_way_platforms
+        .get(entity)
+        .is_ok_and(|platform| !one_way_blocks(platform.up(), normal, velocity))
+}
+
+/// The [`OneWayPlatform`]s the character is currently passing through, ignored until it stops overlapping them.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct PassingThrough(pub Vec<Entity>);
+
+/// Lets a `move_and_slide` hit pass through the [`OneWayPlatform`] it hit if the character isn't blocked by it,
+/// adding the platform to `passing_through`.
+///
+/// Returns `true` if the character passes through, in which case it shouldn't slide along the hit.
+pub fn pass_through_one_way(
+    hit: &mut MoveAndSlideHit,
+    one_way_platforms: &Query<&GlobalTransform, With<OneWayPlatform>>,
+    passing_through: &mut Mut<PassingThrough>,
+) -> bool {
+    if !passes_one_way(
+        one_way_platforms,
+        hit.hit_data.entity,
+        hit.hit_data.normal1,
+        *hit.velocity,
+    ) {
+        return false;
+    }
+
+    // Only mutate when the platform is new, to not rebuild the character filter for nothing
+    if !passing_through.0.contains(&hit.hit_data.entity) {
+        passing_through.0.push(hit.hit_data.entity);
+    }
+    *hit.pass_through = true;
+    true
 }
 
 /// Checks if a surface is walkable based on its slope angle and the up direction.
@@ -137,6 +460,10 @@ pub fn try_climb_step(
 }
 
 /// Sweep in the opposite direction of `up` and return the [`Ground`] if it's walkable.
+///
+/// Edges are resolved with [`resolve_ground`], and the ground normal is averaged with the surfaces found by
+/// [`probe_ground`] to get a stable support plane. A character wedged between unwalkable slopes facing each
+/// other is supported by them. All supporting contacts are written to `contacts`.
 pub fn ground_check(
     collider: &Collider,
     config: MoveAndSlideConfig,
@@ -147,7 +474,11 @@ pub fn ground_check(
     filter: &SpatialQueryFilter,
     floor_check_distance: f32,
     walkable_angle: f32,
+    perch_radius: f32,
+    contacts: &mut Vec<GroundContact>,
 ) -> Option<(f32, Ground)> {
+    contacts.clear();
+
     let (safe_distance, hit) = sweep_check(
         collider,
         config.epsilon,
@@ -159,11 +490,194 @@ pub fn ground_check(
         filter,
     )?;
 
-    let ground = Ground::new_if_walkable(hit.entity, hit.normal1, up, walkable_angle)?;
+    let resolved = resolve_ground(
+        spatial_query,
+        filter,
+        collider,
+        rotation,
+        &hit,
+        translation,
+        up,
+        walkable_angle,
+        perch_radius,
+        floor_check_distance,
+    );
+
+    let swept_normal = resolved
+        .map(|ground| ground.normal)
+        .or(Dir3::new(hit.normal1).ok());
+    if let Some(normal) = swept_normal {
+        contacts.push(GroundContact {
+            entity: hit.entity,
+            point: hit.point1,
+            normal,
+        });
+    }
+
+    probe_ground(
+        spatial_query,
+        filter,
+        collider,
+        translation,
+        rotation,
+        up,
+        floor_check_distance,
+        contacts,
+    );
+
+    let support = Dir3::new(contacts.iter().map(|contact| *contact.normal).sum::<Vec3>())
+        .ok()
+        .filter(|normal| is_walkable(**normal, up, walkable_angle));
+
+    let ground = match resolved {
+        // Smooth out the normal on uneven ground
+        Some(ground) => Ground {
+            normal: support.unwrap_or(ground.normal),
+            ..ground
+        },
+        // Stable footing between unwalkable slopes
+        None if is_wedged(contacts, up, walkable_angle) => Ground {
+            entity: hit.entity,
+            normal: support?,
+        },
+        None => return None,
+    };
 
     Some((safe_distance, ground))
 }
 
+/// A surface supporting a character from below.
+#[derive(Reflect, Debug, PartialEq, Clone, Copy)]
+pub struct GroundContact {
+    pub entity: Entity,
+    pub point: Vec3,
+    pub normal: Dir3,
+}
+
+/// Number of rays around the center of the character cast by [`probe_ground`].
+const GROUND_PROBES: usize = 4;
+
+/// Distance of the probes from the center, relative to the character radius.
+const GROUND_PROBE_OFFSET: f32 = 0.7;
+
+/// Raycast down below the center and around the bottom of the character, adding the surfaces within
+/// `floor_check_distance` of the collider bottom to `contacts`.
+pub fn probe_ground(
+    spatial_query: &SpatialQuery,
+    filter: &SpatialQueryFilter,
+    collider: &Collider,
+    translation: Vec3,
+    rotation: Quat,
+    up: Dir3,
+    floor_check_distance: f32,
+    contacts: &mut Vec<GroundContact>,
+) {
+    let (half_height, radius) = collider_extents(collider, rotation, up);
+    let bottom_center = translation - up * (half_height - radius);
+    let (a, b) = up.any_orthonormal_pair();
+
+    let ring = (0..GROUND_PROBES).map(|i| {
+        let angle = i as f32 / GROUND_PROBES as f32 * 2.0 * PI;
+        (a * angle.cos() + b * angle.sin()) * radius * GROUND_PROBE_OFFSET
+    });
+
+    for offset in [Vec3::ZERO].into_iter().chain(ring) {
+        // Depth of the rounded bottom below its center at this offset
+        let depth = (radius.powi(2) - offset.length_squared()).max(0.0).sqrt();
+        let origin = bottom_center + offset;
+
+        let Some(ray_hit) =
+            spatial_query.cast_ray(origin, -up, depth + floor_check_distance, true, filter)
+        else {
+            continue;
+        };
+
+        // Rays starting inside of a collider have no normal
+        let Ok(normal) = Dir3::new(ray_hit.normal) else {
+            continue;
+        };
+
+        contacts.push(GroundContact {
+            entity: ray_hit.entity,
+            point: origin - up * ray_hit.distance,
+            normal,
+        });
+    }
+}
+
+/// Returns `true` if the `contacts` include unwalkable slopes facing each other, like the sides of a crevice.
+fn is_wedged(contacts: &[GroundContact], up: Dir3, walkable_angle: f32) -> bool {
+    let slopes = || {
+        contacts
+            .iter()
+            .filter(|contact| !is_walkable(*contact.normal, up, walkable_angle))
+            .map(|contact| (contact.point, contact.normal.reject_from_normalized(*up)))
+    };
+
+    // Each slope has to face the other one, slopes facing away from each other are a crest
+    slopes().any(|(a_point, a_normal)| {
+        slopes().any(|(b_point, b_normal)| {
+            (b_point - a_point).dot(a_normal) > 0.0 && (a_point - b_point).dot(b_normal) > 0.0
+        })
+    })
+}
+
+/// How far next to and above a contact point the ground normal is raycast from.
+const GROUND_RAY_INSET: f32 = 0.02;
+
+/// The surface normal and the swept normal differ by more than this on edges, in radians.
+const EDGE_NORMAL_TOLERANCE: f32 = 0.01;
+
+/// Resolve the [`Ground`] of a character at `translation` from a shape-cast `hit` below it.
+///
+/// The swept normal of a rounded capsule bottom changes with how far over an edge the character is,
+/// so the actual surface normal is raycast next to the contact point instead. On an edge, the character
+/// stands within `perch_radius` of the edge, or if there's ground within `step_down_height` below its center.
+pub fn resolve_ground(
+    spatial_query: &SpatialQuery,
+    filter: &SpatialQueryFilter,
+    collider: &Collider,
+    rotation: Quat,
+    hit: &ShapeHitData,
+    translation: Vec3,
+    up: Dir3,
+    walkable_angle: f32,
+    perch_radius: f32,
+    step_down_height: f32,
+) -> Option<Ground> {
+    let offset = (hit.point1 - translation).reject_from_normalized(*up);
+
+    // Raycast just past the contact point, away from the center of the character, so an edge
+    // contact is resolved against the top face the character is hanging over
+    let origin = hit.point1 + offset.normalize_or_zero() * GROUND_RAY_INSET + up * GROUND_RAY_INSET;
+    let normal = spatial_query
+        .cast_ray(origin, -up, GROUND_RAY_INSET * 2.0, true, filter)
+        .filter(|ray_hit| ray_hit.entity == hit.entity && ray_hit.distance > 0.0)
+        .and_then(|ray_hit| Dir3::new(ray_hit.normal).ok())
+        .map_or(hit.normal1, |normal| *normal);
+
+    let on_edge = normal.angle_between(hit.normal1) > EDGE_NORMAL_TOLERANCE;
+    if on_edge && offset.length() > perch_radius {
+        // Too far over the edge, only stand on it when there's lower ground to step down to
+        let (half_height, _) = collider_extents(collider, rotation, up);
+        let reach = half_height + step_down_height;
+        spatial_query.cast_ray(translation, -up, reach, true, filter)?;
+    }
+
+    Ground::new_if_walkable(hit.entity, normal, up, walkable_angle)
+}
+
+/// Half the height of a `collider` along `up` and its radius across `up`, from its bounding box.
+pub fn collider_extents(collider: &Collider, rotation: Quat, up: Dir3) -> (f32, f32) {
+    let half_size = collider.aabb(Vec3::ZERO, rotation).size() / 2.0;
+    let axis = up.abs();
+
+    let half_height = half_size.dot(axis);
+    let radius = half_size.reject_from_normalized(axis).max_element();
+
+    (half_height, radius)
+}
+
 /// Projects a vector on a plane normal.
 ///
 /// The returned vector has different properties depending on whether the plane is walkable or not:
@@ -281,3 +795,26 @@ pub fn motion_on_point(
 ) -> Vec3 {
     transform_moving_point(point, current_transform, previous_transform) - point
 }
+
+/// Get the rotation of a moving transform around the given `axis`, in radians.
+pub fn rotation_on_axis(
+    axis: Dir3,
+    current_transform: &GlobalTransform,
+    previous_transform: &GlobalTransform,
+) -> f32 {
+    let mut rotation = current_transform.rotation() * previous_transform.rotation().inverse();
+
+    // Take the shortest way around, otherwise the angle can jump by a full turn
+    if rotation.w < 0.0 {
+        rotation = -rotation;
+    }
+
+    // Twist part of the swing-twist decomposition around the axis
+    let twist = rotation.xyz().dot(*axis);
+    2.0 * f32::atan2(twist, rotation.w)
+}
+
+/// Get the velocity at the given `point` of a body rotating around `center`.
+pub fn velocity_on_point(point: Vec3, center: Vec3, linear: Vec3, angular: Vec3) -> Vec3 {
+    linear + angular.cross(point - center)
+}
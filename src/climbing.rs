@@ -0,0 +1,384 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::{ActionState, Actions};
+
+use crate::{
+    camera::MainCamera,
+    character::*,
+    input::{self, DefaultContext, Jump},
+    move_and_slide::*,
+    movement::{Character, CharacterFilter, Frozen, movement},
+    movement_mode::{DetectionSet, ExitMovementMode, MovementMode, MovementSet},
+};
+
+/// Lets characters climb colliders marked as [`Climbable`], such as ladders.
+pub struct ClimbingPlugin;
+
+impl Plugin for ClimbingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_required_components::<Character, ClimbingConfig>();
+        app.add_systems(
+            FixedUpdate,
+            (
+                climb_update,
+                climb_detection.in_set(DetectionSet::Climbing),
+                tick_climb_cooldown,
+            )
+                .chain()
+                .after(movement)
+                .in_set(MovementSet::Update),
+        );
+        app.add_systems(Update, climb_jump_input);
+        app.add_observer(exit_climbing);
+    }
+}
+
+/// Marks a collider as climbable.
+///
+/// The climb axis is the character's `up` direction projected on the surface, so slanted surfaces are climbed along their slope.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct Climbable;
+
+/// Configuration of how a character climbs.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct ClimbingConfig {
+    /// How far in front of the character to look for a climbable surface.
+    pub probe_distance: f32,
+    /// Speed along the climb axis.
+    pub speed: f32,
+    /// Speed when moving sideways along the climbable surface.
+    pub strafe_speed: f32,
+    /// The maximum height the character can step up when dismounting at the top.
+    pub top_step_height: f32,
+    /// How far forward the character steps when dismounting at the top.
+    pub dismount_forward: f32,
+    /// Impulse away from the surface when jumping off.
+    pub jump_off_impulse: f32,
+    /// Impulse along `up` when jumping off.
+    pub jump_off_up_impulse: f32,
+    /// Time after letting go before the character can climb again, in seconds.
+    pub remount_cooldown: f32,
+}
+
+impl Default for ClimbingConfig {
+    fn default() -> Self {
+        Self {
+            probe_distance: 0.2,
+            speed: 3.0,
+            strafe_speed: 1.5,
+            top_step_height: 0.5,
+            dismount_forward: 0.7,
+            jump_off_impulse: 4.0,
+            jump_off_up_impulse: EXAMPLE_JUMP_IMPULSE / 2.0,
+            remount_cooldown: 0.3,
+        }
+    }
+}
+
+/// Inserted on a character while it's climbing.
+///
+/// The character is in [`MovementMode::Climbing`] while this component is present.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct Climbing {
+    /// The entity being climbed.
+    pub entity: Entity,
+    /// The normal of the climbed surface, pointing towards the character.
+    pub normal: Dir3,
+}
+
+impl Climbing {
+    /// The direction to climb along, this is the `up` direction projected on the climbed surface.
+    pub fn axis(&self, up: Dir3) -> Dir3 {
+        Dir3::new(up.reject_from_normalized(*self.normal)).unwrap_or(up)
+    }
+}
+
+/// Prevents a character from climbing until the remaining time runs out.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct ClimbCooldown(pub f32);
+
+/// Sweep in the given `direction` and return the safe distance, entity and normal of a [`Climbable`] surface.
+fn find_climbable(
+    spatial_query: &SpatialQuery,
+    collider: &Collider,
+    translation: Vec3,
+    rotation: Quat,
+    direction: Dir3,
+    config: &ClimbingConfig,
+    epsilon: f32,
+    filter: &SpatialQueryFilter,
+    climbables: &Query<(), With<Climbable>>,
+) -> Option<(f32, Entity, Dir3)> {
+    let (safe_distance, hit) = sweep_check(
+        collider,
+        epsilon,
+        translation,
+        direction,
+        config.probe_distance,
+        rotation,
+        spatial_query,
+        filter,
+    )?;
+
+    if !climbables.contains(hit.entity) {
+        return None;
+    }
+
+    let normal = Dir3::new(hit.normal1).ok()?;
+
+    Some((safe_distance, hit.entity, normal))
+}
+
+fn climb_detection(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Actions<DefaultContext>,
+            &Transform,
+            &mut Character,
+            &mut MovementMode,
+            &Collider,
+            &CharacterFilter,
+            &ClimbingConfig,
+        ),
+        (Without<Frozen>, Without<ClimbCooldown>),
+    >,
+    climbables: Query<(), With<Climbable>>,
+    main_camera: Single<&Transform, (With<MainCamera>, Without<Character>)>,
+    spatial_query: SpatialQuery,
+) {
+    let main_camera_transform = main_camera.into_inner();
+    for (entity, actions, transform, mut character, mut mode, collider, filter, config) in
+        &mut query
+    {
+        if !matches!(*mode, MovementMode::Walking | MovementMode::Falling) {
+            continue;
+        }
+
+        let input_vec = actions.action::<input::Move>().value().as_axis2d();
+
+        // Only climb surfaces the character is pushing into
+        let camera_yaw = main_camera_transform.rotation.to_euler(EulerRot::YXZ).0;
+        let yaw_rotation = Quat::from_rotation_y(camera_yaw);
+        let Ok(direction) = Dir3::new(yaw_rotation * Vec3::new(input_vec.x, 0.0, -input_vec.y))
+        else {
+            continue;
+        };
+
+        let Some((_, climbable, normal)) = find_climbable(
+            &spatial_query,
+            collider,
+            transform.translation,
+            transform.rotation,
+            direction,
+            config,
+            character.config.epsilon,
+            &filter.0,
+            &climbables,
+        ) else {
+            continue;
+        };
+
+        if normal.dot(*direction) > -0.5 {
+            continue;
+        }
+
+        character.velocity = Vec3::ZERO;
+        character.ground = None;
+        *mode = MovementMode::Climbing;
+
+        commands.entity(entity).insert(Climbing {
+            entity: climbable,
+            normal,
+        });
+    }
+}
+
+fn climb_update(
+    mut query: Query<
+        (
+            &Actions<DefaultContext>,
+            &mut Transform,
+            &mut Character,
+            &mut MovementMode,
+            &mut Climbing,
+            &mut PassingThrough,
+            &Collider,
+            &CharacterFilter,
+            &ClimbingConfig,
+        ),
+        Without<Frozen>,
+    >,
+    climbables: Query<(), With<Climbable>>,
+    one_way_platforms: Query<&GlobalTransform, With<OneWayPlatform>>,
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+) {
+    for (
+        actions,
+        mut transform,
+        mut character,
+        mut mode,
+        mut climbing,
+        mut passing_through,
+        collider,
+        filter,
+        config,
+    ) in &mut query
+    {
+        if *mode != MovementMode::Climbing {
+            continue;
+        }
+
+        let up = character.up;
+        let input_vec = actions.action::<input::Move>().value().as_axis2d();
+
+        // Forward input climbs along the climb axis, no gravity is applied while climbing
+        let right = up.cross(*climbing.normal);
+        character.velocity = climbing.axis(up) * input_vec.y * config.speed
+            + right * input_vec.x * config.strafe_speed;
+
+        let mut new_ground = None;
+
+        let move_result = move_and_slide(
+            &spatial_query,
+            collider,
+            transform.translation,
+            character.velocity,
+            transform.rotation,
+            character.config,
+            &filter.0,
+            time.delta_secs(),
+            |hit| {
+                if pass_through_one_way(hit, &one_way_platforms, &mut passing_through) {
+                    return false;
+                }
+
+                // Reached the bottom while climbing down
+                if input_vec.y < 0.0 {
+                    if let Some(ground) = Ground::new_if_walkable(
+                        hit.hit_data.entity,
+                        hit.hit_data.normal1,
+                        character.up,
+                        EXAMPLE_WALKABLE_ANGLE,
+                    ) {
+                        new_ground = Some(ground);
+                    }
+                }
+
+                character.velocity = character.velocity.reject_from(hit.hit_data.normal1);
+
+                true
+            },
+        );
+
+        transform.translation = move_result.new_translation;
+
+        // Get off at the bottom
+        if new_ground.is_some() {
+            character.ground = new_ground;
+            character.velocity = Vec3::ZERO;
+            *mode = MovementMode::Walking;
+            continue;
+        }
+
+        // Make sure the climbable surface is still there and stay attached to it
+        if let Some((safe_distance, climbable, normal)) = find_climbable(
+            &spatial_query,
+            collider,
+            transform.translation,
+            transform.rotation,
+            -climbing.normal,
+            config,
+            character.config.epsilon,
+            &filter.0,
+            &climbables,
+        ) {
+            transform.translation -= normal * safe_distance;
+            *climbing = Climbing {
+                entity: climbable,
+                normal,
+            };
+            continue;
+        }
+
+        character.velocity = Vec3::ZERO;
+        *mode = MovementMode::Falling;
+
+        // Climbed past the top, step forward onto the ledge
+        if input_vec.y > 0.0 {
+            if let Some((translation, hit)) = try_climb_step(
+                &spatial_query,
+                collider,
+                transform.translation,
+                -climbing.normal * config.dismount_forward,
+                transform.rotation,
+                up,
+                config.top_step_height,
+                character.config.epsilon,
+                &filter.0,
+            ) {
+                if let Some(ground) =
+                    Ground::new_if_walkable(hit.entity, hit.normal1, up, EXAMPLE_WALKABLE_ANGLE)
+                {
+                    transform.translation = translation;
+                    character.ground = Some(ground);
+                    *mode = MovementMode::Walking;
+                }
+            }
+        }
+    }
+}
+
+fn tick_climb_cooldown(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut ClimbCooldown)>,
+    time: Res<Time>,
+) {
+    for (entity, mut cooldown) in &mut query {
+        cooldown.0 -= time.delta_secs();
+
+        if cooldown.0 <= 0.0 {
+            commands.entity(entity).remove::<ClimbCooldown>();
+        }
+    }
+}
+
+fn climb_jump_input(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut Character,
+        &mut MovementMode,
+        &Climbing,
+        &ClimbingConfig,
+        &Actions<DefaultContext>,
+    )>,
+) {
+    for (entity, mut character, mut mode, climbing, config, actions) in &mut query {
+        if *mode != MovementMode::Climbing || actions.action::<Jump>().state() != ActionState::Fired
+        {
+            continue;
+        }
+
+        let up = character.up;
+        character
+            .launch(climbing.normal * config.jump_off_impulse + up * config.jump_off_up_impulse);
+        *mode = MovementMode::Falling;
+
+        commands
+            .entity(entity)
+            .insert(ClimbCooldown(config.remount_cooldown));
+    }
+}
+
+fn exit_climbing(trigger: Trigger<ExitMovementMode>, mut commands: Commands) {
+    if trigger.0 == MovementMode::Climbing {
+        commands.entity(trigger.target()).remove::<Climbing>();
+    }
+}
@@ -0,0 +1,350 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::{ActionState, Actions};
+
+use crate::{
+    camera::MainCamera,
+    character::*,
+    input::{self, Crouch, DefaultContext, Jump},
+    move_and_slide::*,
+    movement::{
+        Character, CharacterFilter, Frozen, GroundContacts, friction, jump_input, movement,
+    },
+    movement_mode::{DetectionSet, ExitMovementMode, MovementMode, MovementSet},
+};
+
+/// The [`MovementMode`] of a character sliding on the ground while crouched.
+pub const CROUCH_SLIDE_MODE: MovementMode = MovementMode::Custom("crouch_slide");
+
+/// Lets characters crouch while moving fast to slide along the ground, keeping their momentum.
+pub struct CrouchSlidePlugin;
+
+impl Plugin for CrouchSlidePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_required_components::<Character, CrouchSlideConfig>();
+        app.add_systems(
+            FixedUpdate,
+            (
+                crouch_slide_update,
+                crouch_slide_detection.in_set(DetectionSet::CrouchSlide),
+                tick_crouch_slide_cooldown,
+            )
+                .chain()
+                .after(movement)
+                .in_set(MovementSet::Update),
+        );
+        app.add_systems(Update, slide_jump_input.after(jump_input));
+        app.add_observer(exit_crouch_slide);
+    }
+}
+
+/// Configuration of crouch sliding.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct CrouchSlideConfig {
+    /// The minimum horizontal speed needed to start sliding.
+    pub min_start_speed: f32,
+    /// The slide ends when the character slows down below this speed.
+    pub min_exit_speed: f32,
+    /// The slide speed is clamped to this.
+    pub max_speed: f32,
+    /// Constant deceleration while sliding, much lower than walking friction.
+    pub friction: f32,
+    /// Fraction of gravity accelerating the character down slopes and slowing it down up slopes.
+    pub gravity_scale: f32,
+    /// How quickly the slide turns towards the input direction, in radians per second.
+    pub turn_rate: f32,
+    /// Speed added in the slide direction when jumping out of a slide.
+    pub jump_boost: f32,
+    /// Time after a slide ends before the character can slide again, in seconds.
+    pub cooldown: f32,
+}
+
+impl Default for CrouchSlideConfig {
+    fn default() -> Self {
+        Self {
+            min_start_speed: EXAMPLE_MOVEMENT_SPEED * 0.75,
+            min_exit_speed: 3.0,
+            max_speed: 25.0,
+            friction: 4.0,
+            gravity_scale: 1.0,
+            turn_rate: 1.0,
+            jump_boost: 2.0,
+            cooldown: 0.5,
+        }
+    }
+}
+
+/// Inserted on a character while it's crouch sliding.
+///
+/// The character is in [`CROUCH_SLIDE_MODE`] while this component is present.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct CrouchSliding {
+    /// Time since the slide started, in seconds.
+    pub elapsed: f32,
+}
+
+/// Prevents a character from crouch sliding until the remaining time runs out.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct CrouchSlideCooldown(pub f32);
+
+fn crouch_slide_detection(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Actions<DefaultContext>,
+            &Character,
+            &mut MovementMode,
+            &CrouchSlideConfig,
+        ),
+        (Without<Frozen>, Without<CrouchSlideCooldown>),
+    >,
+) {
+    for (entity, actions, character, mut mode, config) in &mut query {
+        if *mode != MovementMode::Walking
+            || !character.grounded()
+            || actions.action::<Crouch>().state() != ActionState::Fired
+        {
+            continue;
+        }
+
+        let horizontal = character.velocity.reject_from_normalized(*character.up);
+        if horizontal.length() < config.min_start_speed {
+            continue;
+        }
+
+        *mode = CROUCH_SLIDE_MODE;
+
+        commands
+            .entity(entity)
+            .insert(CrouchSliding { elapsed: 0.0 });
+    }
+}
+
+fn crouch_slide_update(
+    mut query: Query<
+        (
+            &Actions<DefaultContext>,
+            &mut Transform,
+            &mut Character,
+            &mut MovementMode,
+            &mut CrouchSliding,
+            &mut GroundContacts,
+            &mut PassingThrough,
+            &Collider,
+            &CharacterFilter,
+            &CrouchSlideConfig,
+        ),
+        Without<Frozen>,
+    >,
+    main_camera: Single<&Transform, (With<MainCamera>, Without<Character>)>,
+    one_way_platforms: Query<&GlobalTransform, With<OneWayPlatform>>,
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+) {
+    let main_camera_transform = main_camera.into_inner();
+    for (
+        actions,
+        mut transform,
+        mut character,
+        mut mode,
+        mut slide,
+        mut contacts,
+        mut passing_through,
+        collider,
+        filter,
+        config,
+    ) in &mut query
+    {
+        if *mode != CROUCH_SLIDE_MODE {
+            continue;
+        }
+
+        slide.elapsed += time.delta_secs();
+
+        // Letting go of crouch stands back up
+        if actions.action::<Crouch>().state() != ActionState::Fired {
+            *mode = MovementMode::Walking;
+            continue;
+        }
+
+        let up = character.up;
+        let Some(ground) = character.ground else {
+            *mode = MovementMode::Falling;
+            continue;
+        };
+
+        // Keep the velocity on the slope plane
+        character.velocity = project_motion_on_ground(character.velocity, *ground.normal, up);
+
+        // Speed up going downhill and slow down going uphill
+        let slope_gravity = (up * -EXAMPLE_GRAVITY).reject_from_normalized(*ground.normal);
+        character.velocity += slope_gravity * config.gravity_scale * time.delta_secs();
+
+        let friction = friction(character.velocity, config.friction, time.delta_secs());
+        character.velocity += friction;
+
+        // Turn the slide towards the input direction without changing its speed
+        let input_vec = actions.action::<input::Move>().value().as_axis2d();
+        let camera_yaw = main_camera_transform.rotation.to_euler(EulerRot::YXZ).0;
+        let yaw_rotation = Quat::from_rotation_y(camera_yaw);
+        let direction = yaw_rotation * Vec3::new(input_vec.x, 0.0, -input_vec.y);
+
+        let speed = character.velocity.length();
+        if let Ok(forward) = Dir3::new(character.velocity) {
+            let across = direction
+                .reject_from_normalized(*ground.normal)
+                .reject_from_normalized(*forward);
+            let turned = *forward + across * config.turn_rate * time.delta_secs();
+            character.velocity = turned.normalize_or_zero() * speed;
+        }
+
+        character.velocity = character.velocity.clamp_length_max(config.max_speed);
+
+        if character.velocity.reject_from_normalized(*up).length() < config.min_exit_speed {
+            *mode = MovementMode::Walking;
+            continue;
+        }
+
+        let mut new_ground = None;
+
+        let move_result = move_and_slide(
+            &spatial_query,
+            collider,
+            transform.translation,
+            character.velocity,
+            transform.rotation,
+            character.config,
+            &filter.0,
+            time.delta_secs(),
+            |hit| {
+                if pass_through_one_way(hit, &one_way_platforms, &mut passing_through) {
+                    return false;
+                }
+
+                if let Some(ground) = Ground::new_if_walkable(
+                    hit.hit_data.entity,
+                    hit.hit_data.normal1,
+                    up,
+                    EXAMPLE_WALKABLE_ANGLE,
+                ) {
+                    new_ground = Some(ground);
+                    *hit.velocity = project_motion_on_ground(*hit.velocity, *ground.normal, up);
+                    character.velocity =
+                        project_motion_on_ground(character.velocity, *ground.normal, up);
+                    return true;
+                }
+
+                // Avoid sliding up walls
+                *hit.velocity = project_motion_on_wall(*hit.velocity, hit.hit_data.normal1, up);
+                character.velocity =
+                    project_motion_on_wall(character.velocity, hit.hit_data.normal1, up);
+
+                true
+            },
+        );
+
+        transform.translation = move_result.new_translation;
+
+        // Stay on the ground like regular movement does, sliding off of a ledge or over a crest keeps the momentum
+        let snapping = character.ground_snapping;
+        match ground_check(
+            collider,
+            character.config,
+            transform.translation,
+            up,
+            transform.rotation,
+            &spatial_query,
+            &filter.0,
+            EXAMPLE_GROUND_CHECK_DISTANCE.max(character.ground_snapping.step_down_height),
+            EXAMPLE_WALKABLE_ANGLE,
+            character.edges.perch_radius,
+            &mut contacts.0,
+        )
+        .filter(|(_, target)| {
+            !passes_one_way(
+                &one_way_platforms,
+                target.entity,
+                *target.normal,
+                character.velocity,
+            )
+        })
+        .filter(|(safe_distance, target)| {
+            snapping.keeps_ground(
+                character.velocity,
+                *safe_distance,
+                EXAMPLE_GROUND_CHECK_DISTANCE,
+                &ground,
+                target,
+            )
+        }) {
+            Some((safe_distance, ground)) => {
+                transform.translation -= up * safe_distance;
+                character.ground = Some(ground);
+            }
+            None => {
+                character.ground = new_ground;
+                if new_ground.is_none() {
+                    *mode = MovementMode::Falling;
+                }
+            }
+        }
+    }
+}
+
+fn tick_crouch_slide_cooldown(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut CrouchSlideCooldown)>,
+    time: Res<Time>,
+) {
+    for (entity, mut cooldown) in &mut query {
+        cooldown.0 -= time.delta_secs();
+
+        if cooldown.0 <= 0.0 {
+            commands.entity(entity).remove::<CrouchSlideCooldown>();
+        }
+    }
+}
+
+/// Boosts the slide direction when jumping out of a slide.
+///
+/// The jump itself is handled by regular jump input, since the character is grounded while sliding.
+fn slide_jump_input(
+    mut query: Query<(
+        &mut Character,
+        &mut MovementMode,
+        &CrouchSlideConfig,
+        &Actions<DefaultContext>,
+    )>,
+) {
+    for (mut character, mut mode, config, actions) in &mut query {
+        if *mode != CROUCH_SLIDE_MODE || actions.action::<Jump>().state() != ActionState::Fired {
+            continue;
+        }
+
+        let up = character.up;
+        let horizontal = character.velocity.reject_from_normalized(*up);
+        character.velocity += horizontal.normalize_or_zero() * config.jump_boost;
+        *mode = MovementMode::Falling;
+    }
+}
+
+fn exit_crouch_slide(
+    trigger: Trigger<ExitMovementMode>,
+    mut commands: Commands,
+    query: Query<&CrouchSlideConfig>,
+) {
+    if trigger.0 != CROUCH_SLIDE_MODE {
+        return;
+    }
+
+    let mut entity = commands.entity(trigger.target());
+    entity.remove::<CrouchSliding>();
+
+    if let Ok(config) = query.get(trigger.target()) {
+        entity.insert(CrouchSlideCooldown(config.cooldown));
+    }
+}
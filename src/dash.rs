@@ -0,0 +1,253 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::{ActionState, Actions};
+
+use crate::{
+    camera::MainCamera,
+    character::*,
+    input::{self, Dash, DefaultContext},
+    move_and_slide::*,
+    movement::{Character, CharacterFilter, Frozen, movement},
+    movement_mode::{ExitMovementMode, MovementMode, MovementSet},
+};
+
+/// The [`MovementMode`] of a dashing character.
+pub const DASH_MODE: MovementMode = MovementMode::Custom("dash");
+
+/// Lets characters dash a short distance at high speed.
+pub struct DashPlugin;
+
+impl Plugin for DashPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_required_components::<Character, DashConfig>();
+        app.add_systems(
+            FixedUpdate,
+            (dash_update, tick_dash_cooldown)
+                .chain()
+                .after(movement)
+                .in_set(MovementSet::Update),
+        );
+        app.add_systems(Update, dash_input);
+        app.add_observer(exit_dash);
+    }
+}
+
+/// Which direction a character dashes in.
+#[derive(Reflect, Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum DashDirection {
+    /// The move input direction relative to the camera yaw, falling back to the look direction without input.
+    #[default]
+    Input,
+    /// The look direction of the camera.
+    Look,
+}
+
+/// What happens when a dashing character hits something that isn't walkable.
+#[derive(Reflect, Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum DashHitBehaviour {
+    /// End the dash at the point of impact.
+    Stop,
+    /// Keep dashing along the surface.
+    #[default]
+    Slide,
+    /// Reflect off of the surface and keep dashing.
+    Bounce,
+}
+
+/// Configuration of how a character dashes.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct DashConfig {
+    /// Speed of the dash, friction is ignored while dashing.
+    pub speed: f32,
+    /// Duration of the dash, in seconds.
+    pub duration: f32,
+    /// The character doesn't fall while dashing if set.
+    pub ignore_gravity: bool,
+    /// The velocity is clamped to this speed when the dash ends.
+    pub end_speed: f32,
+    /// Time after the start of a dash before the character can dash again, in seconds.
+    pub cooldown: f32,
+    pub direction: DashDirection,
+    pub on_hit: DashHitBehaviour,
+}
+
+impl Default for DashConfig {
+    fn default() -> Self {
+        Self {
+            speed: 25.0,
+            duration: 0.2,
+            ignore_gravity: true,
+            end_speed: EXAMPLE_MOVEMENT_SPEED,
+            cooldown: 1.0,
+            direction: DashDirection::Input,
+            on_hit: DashHitBehaviour::Slide,
+        }
+    }
+}
+
+/// Inserted on a character while it's dashing.
+///
+/// The character is in [`DASH_MODE`] while this component is present.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct Dashing {
+    /// Time since the dash started, in seconds.
+    pub elapsed: f32,
+}
+
+/// Prevents a character from dashing until the remaining time runs out.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct DashCooldown(pub f32);
+
+fn dash_update(
+    mut query: Query<
+        (
+            &mut Transform,
+            &mut Character,
+            &mut MovementMode,
+            &mut Dashing,
+            &mut PassingThrough,
+            &Collider,
+            &CharacterFilter,
+            &DashConfig,
+        ),
+        Without<Frozen>,
+    >,
+    one_way_platforms: Query<&GlobalTransform, With<OneWayPlatform>>,
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+) {
+    for (
+        mut transform,
+        mut character,
+        mut mode,
+        mut dash,
+        mut passing_through,
+        collider,
+        filter,
+        config,
+    ) in &mut query
+    {
+        if *mode != DASH_MODE {
+            continue;
+        }
+
+        dash.elapsed += time.delta_secs();
+
+        if !config.ignore_gravity {
+            let gravity = character.up * -EXAMPLE_GRAVITY * time.delta_secs();
+            character.velocity += gravity;
+        }
+
+        let up = character.up;
+        let mut stopped = false;
+
+        let move_result = move_and_slide(
+            &spatial_query,
+            collider,
+            transform.translation,
+            character.velocity,
+            transform.rotation,
+            character.config,
+            &filter.0,
+            time.delta_secs(),
+            |hit| {
+                if pass_through_one_way(hit, &one_way_platforms, &mut passing_through) {
+                    return false;
+                }
+
+                // Always slide along walkable surfaces, so dashing up a ramp doesn't count as a hit
+                if is_walkable(hit.hit_data.normal1, up, EXAMPLE_WALKABLE_ANGLE) {
+                    return true;
+                }
+
+                match config.on_hit {
+                    DashHitBehaviour::Stop => {
+                        *hit.velocity = Vec3::ZERO;
+                        *hit.remaining_time = 0.0;
+                        stopped = true;
+                        false
+                    }
+                    DashHitBehaviour::Slide => true,
+                    DashHitBehaviour::Bounce => {
+                        *hit.velocity = hit.velocity.reflect(hit.hit_data.normal1);
+                        false
+                    }
+                }
+            },
+        );
+
+        transform.translation = move_result.new_translation;
+        character.velocity = move_result.new_velocity;
+
+        if stopped || dash.elapsed >= config.duration {
+            character.velocity = character.velocity.clamp_length_max(config.end_speed);
+            *mode = MovementMode::Falling;
+        }
+    }
+}
+
+fn tick_dash_cooldown(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut DashCooldown)>,
+    time: Res<Time>,
+) {
+    for (entity, mut cooldown) in &mut query {
+        cooldown.0 -= time.delta_secs();
+
+        if cooldown.0 <= 0.0 {
+            commands.entity(entity).remove::<DashCooldown>();
+        }
+    }
+}
+
+fn dash_input(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &mut Character,
+            &mut MovementMode,
+            &DashConfig,
+            &Actions<DefaultContext>,
+        ),
+        (Without<Frozen>, Without<DashCooldown>),
+    >,
+    main_camera: Single<&Transform, (With<MainCamera>, Without<Character>)>,
+) {
+    let main_camera_transform = main_camera.into_inner();
+    for (entity, mut character, mut mode, config, actions) in &mut query {
+        if !matches!(*mode, MovementMode::Walking | MovementMode::Falling)
+            || actions.action::<Dash>().state() != ActionState::Fired
+        {
+            continue;
+        }
+
+        let look = main_camera_transform.forward();
+        let direction = match config.direction {
+            DashDirection::Input => {
+                let input_vec = actions.action::<input::Move>().value().as_axis2d();
+                let camera_yaw = main_camera_transform.rotation.to_euler(EulerRot::YXZ).0;
+                let yaw_rotation = Quat::from_rotation_y(camera_yaw);
+                Dir3::new(yaw_rotation * Vec3::new(input_vec.x, 0.0, -input_vec.y)).unwrap_or(look)
+            }
+            DashDirection::Look => look,
+        };
+
+        character.velocity = direction * config.speed;
+        character.ground = None;
+        *mode = DASH_MODE;
+
+        commands
+            .entity(entity)
+            .insert((Dashing { elapsed: 0.0 }, DashCooldown(config.cooldown)));
+    }
+}
+
+fn exit_dash(trigger: Trigger<ExitMovementMode>, mut commands: Commands) {
+    if trigger.0 == DASH_MODE {
+        commands.entity(trigger.target()).remove::<Dashing>();
+    }
+}
@@ -0,0 +1,143 @@
+use bevy::prelude::*;
+
+use crate::movement::Character;
+
+/// Impulses and timed forces queued on a character.
+///
+/// They are resolved once per tick by regular movement, so any number of systems can push the character
+/// without writing to its velocity directly. Forces queued while the character is in another movement mode
+/// are held until it's back to walking or falling.
+#[derive(Component, Reflect, Default, Debug, Clone)]
+#[reflect(Component)]
+pub struct ExternalForces {
+    forces: Vec<CharacterForce>,
+}
+
+impl ExternalForces {
+    /// Queue an impulse or timed force.
+    pub fn add(&mut self, force: CharacterForce) {
+        self.forces.push(force);
+    }
+
+    /// Queue an impulse that's applied on the next tick.
+    pub fn add_impulse(&mut self, impulse: Vec3) {
+        self.add(CharacterForce::impulse(impulse));
+    }
+
+    /// Queue a constant acceleration that's applied every tick for `duration` seconds.
+    pub fn add_force(&mut self, force: Vec3, duration: f32) {
+        self.add(CharacterForce::force(force, duration));
+    }
+
+    /// Removes all queued impulses and forces.
+    pub fn clear(&mut self) {
+        self.forces.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.forces.is_empty()
+    }
+
+    /// Applies the queued impulses and forces to the `character`'s velocity and drops the expired ones.
+    pub(crate) fn resolve(&mut self, character: &mut Character, delta: f32) {
+        self.forces.retain_mut(|force| {
+            let skip = force.ignore_while_grounded && character.grounded();
+
+            match &mut force.kind {
+                CharacterForceKind::Impulse => {
+                    // Held until the character leaves the ground
+                    if skip {
+                        return true;
+                    }
+
+                    apply(character, force.value, force.clear_ground);
+
+                    false
+                }
+                CharacterForceKind::Force { remaining } => {
+                    let step = remaining.min(delta);
+                    *remaining -= delta;
+
+                    if !skip {
+                        apply(character, force.value * step, force.clear_ground);
+                    }
+
+                    force.value *= f32::exp(-force.decay * delta);
+
+                    *remaining > 0.0
+                }
+            }
+        });
+    }
+}
+
+fn apply(character: &mut Character, velocity: Vec3, clear_ground: bool) {
+    if clear_ground {
+        character.ground = None;
+    }
+
+    character.velocity += velocity;
+}
+
+/// Whether an [`CharacterForce`] is applied once or over time.
+#[derive(Reflect, Debug, PartialEq, Clone, Copy)]
+pub enum CharacterForceKind {
+    /// Applied as a change in velocity on the next tick.
+    Impulse,
+    /// Applied as an acceleration every tick until the remaining time runs out.
+    Force { remaining: f32 },
+}
+
+/// An impulse or timed force queued in [`ExternalForces`].
+#[derive(Reflect, Debug, Clone, Copy)]
+pub struct CharacterForce {
+    pub kind: CharacterForceKind,
+    /// The impulse, or the acceleration of a timed force.
+    pub value: Vec3,
+    /// Exponential decay rate of a timed force, per second.
+    pub decay: f32,
+    /// Skip this force while the character is grounded, impulses stay queued until it's airborne and timed
+    /// forces keep running out.
+    pub ignore_while_grounded: bool,
+    /// Clear the ground of the character when this force is applied.
+    pub clear_ground: bool,
+}
+
+impl CharacterForce {
+    pub fn impulse(impulse: Vec3) -> Self {
+        Self {
+            kind: CharacterForceKind::Impulse,
+            value: impulse,
+            decay: 0.0,
+            ignore_while_grounded: false,
+            clear_ground: false,
+        }
+    }
+
+    pub fn force(force: Vec3, duration: f32) -> Self {
+        Self {
+            kind: CharacterForceKind::Force {
+                remaining: duration,
+            },
+            value: force,
+            decay: 0.0,
+            ignore_while_grounded: false,
+            clear_ground: false,
+        }
+    }
+
+    pub fn with_decay(mut self, decay: f32) -> Self {
+        self.decay = decay;
+        self
+    }
+
+    pub fn ignore_while_grounded(mut self) -> Self {
+        self.ignore_while_grounded = true;
+        self
+    }
+
+    pub fn clear_ground(mut self) -> Self {
+        self.clear_ground = true;
+        self
+    }
+}
@@ -18,6 +18,18 @@ pub struct Look; // Camera look/rotation
 #[input_action(output = bool)]
 pub struct Jump;
 
+#[derive(Debug, Clone, Copy, InputAction)]
+#[input_action(output = bool)]
+pub struct Sprint;
+
+#[derive(Debug, Clone, Copy, InputAction)]
+#[input_action(output = bool)]
+pub struct Dash;
+
+#[derive(Debug, Clone, Copy, InputAction)]
+#[input_action(output = bool)]
+pub struct Crouch;
+
 #[derive(Debug, Clone, Copy, InputAction)]
 #[input_action(output = bool)]
 pub struct CaptureCursor;
@@ -34,6 +46,20 @@ pub struct ToggleViewPerspective;
 #[input_action(output = bool)]
 pub struct ToggleFlyCam;
 
+#[derive(Debug, Clone, Copy, InputAction)]
+#[input_action(output = bool)]
+pub struct ToggleNoclip;
+
+// --- Noclip Actions ---
+
+#[derive(InputAction, Debug, Clone, Copy)]
+#[input_action(output = f32)]
+pub(crate) struct NoclipFly;
+
+#[derive(InputAction, Debug, Clone, Copy)]
+#[input_action(output = f32)]
+pub(crate) struct AdjustNoclipSpeed;
+
 // --- Fly Camera Specific Actions ---
 
 #[derive(InputAction, Debug, Clone, Copy)]
@@ -108,6 +134,19 @@ fn bind_default_context_actions(
             .to((KeyCode::Space, GamepadButton::East))
             .with_conditions(JustPress::default());
 
+        actions
+            .bind::<Sprint>()
+            .to((KeyCode::ShiftLeft, GamepadButton::LeftTrigger2));
+
+        actions
+            .bind::<Dash>()
+            .to((MouseButton::Right, GamepadButton::West))
+            .with_conditions(JustPress::default());
+
+        actions
+            .bind::<Crouch>()
+            .to((KeyCode::ControlLeft, GamepadButton::RightThumb));
+
         // --- Camera Look (Used by FPS, potentially others if not overridden) ---
         actions.bind::<Look>().to((
             Input::mouse_motion().with_modifiers((Scale::splat(0.05), Negate::all())),
@@ -124,6 +163,35 @@ fn bind_default_context_actions(
             .bind::<ToggleFlyCam>()
             .to((KeyCode::KeyF, GamepadButton::DPadUp))
             .with_conditions(JustPress::default());
+        actions
+            .bind::<ToggleNoclip>()
+            .to((KeyCode::KeyN, GamepadButton::DPadLeft))
+            .with_conditions(JustPress::default());
+
+        // --- Noclip ---
+        // Gamepad East is Jump, so flying up uses South instead
+        actions.bind::<NoclipFly>().to((
+            Bidirectional {
+                positive: KeyCode::KeyE,
+                negative: KeyCode::KeyQ,
+            },
+            Bidirectional {
+                positive: GamepadButton::South,
+                negative: GamepadButton::LeftThumb,
+            },
+        ));
+
+        // Speed up or slow down while in noclip
+        actions.bind::<AdjustNoclipSpeed>().to((
+            Bidirectional {
+                positive: KeyCode::BracketRight,
+                negative: KeyCode::BracketLeft,
+            },
+            Bidirectional {
+                positive: GamepadButton::RightTrigger,
+                negative: GamepadButton::LeftTrigger,
+            },
+        ));
     } else {
         warn!(
             "Failed to get Actions<DefaultContext> for entity {:?} during binding",
@@ -0,0 +1,48 @@
+use avian3d::prelude::*;
+
+/// The collision layers used by the prototype.
+///
+/// Use [`GameLayer::layers`] to get the [`CollisionLayers`] of an entity that's a member of a layer.
+#[derive(PhysicsLayer, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameLayer {
+    /// Static level geometry, blocks characters and cameras.
+    #[default]
+    World,
+    /// Characters, ignored by character movement and cameras.
+    Character,
+    /// Moving platforms, blocks characters and cameras.
+    Platform,
+    /// Sensor volumes like water, ignored by character movement and cameras.
+    Trigger,
+    /// Blocks cameras but not characters.
+    CameraBlocker,
+    /// Blocks characters and cameras, but characters can't step up onto it.
+    NoStep,
+}
+
+impl GameLayer {
+    /// The layers character movement collides with.
+    pub const CHARACTER_BLOCKERS: [GameLayer; 3] =
+        [GameLayer::World, GameLayer::Platform, GameLayer::NoStep];
+
+    /// The layers that keep cameras from clipping into geometry.
+    pub const CAMERA_BLOCKERS: [GameLayer; 4] = [
+        GameLayer::World,
+        GameLayer::Platform,
+        GameLayer::NoStep,
+        GameLayer::CameraBlocker,
+    ];
+
+    /// Returns the [`CollisionLayers`] of an entity that's a member of this layer.
+    pub fn layers(self) -> CollisionLayers {
+        let filters = match self {
+            GameLayer::World | GameLayer::Platform | GameLayer::NoStep => LayerMask::ALL,
+            GameLayer::Character => Self::CHARACTER_BLOCKERS.into(),
+            GameLayer::Trigger => GameLayer::Character.into(),
+            // Only blocks camera queries, never collides with bodies
+            GameLayer::CameraBlocker => LayerMask::NONE,
+        };
+
+        CollisionLayers::new(self, filters)
+    }
+}
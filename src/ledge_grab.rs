@@ -0,0 +1,518 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::{ActionState, Actions};
+
+use crate::{
+    camera::MainCamera,
+    character::*,
+    input::{self, DefaultContext, Jump},
+    move_and_slide::*,
+    movement::{Character, CharacterFilter, Frozen, movement},
+    movement_mode::{DetectionSet, ExitMovementMode, MovementMode, MovementSet},
+};
+
+/// The [`MovementMode`] of a character hanging from, or mantling up, a ledge.
+pub const LEDGE_GRAB_MODE: MovementMode = MovementMode::Custom("ledge_grab");
+
+/// Distance from the character's center to the bottom of the capsule.
+const CHARACTER_HALF_HEIGHT: f32 =
+    EXAMPLE_CHARACTER_CAPSULE_LENGTH / 2.0 + EXAMPLE_CHARACTER_RADIUS;
+
+/// Lets airborne characters grab onto ledges, shimmy along them and mantle up.
+pub struct LedgeGrabPlugin;
+
+impl Plugin for LedgeGrabPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_required_components::<Character, LedgeGrabConfig>();
+        app.add_systems(
+            FixedUpdate,
+            (
+                ledge_detection.in_set(DetectionSet::LedgeGrab),
+                ledge_grab_update,
+                tick_ledge_grab_cooldown,
+            )
+                .chain()
+                .after(movement)
+                .in_set(MovementSet::Update),
+        );
+        app.add_systems(Update, ledge_jump_input);
+        app.add_observer(exit_ledge_grab);
+    }
+}
+
+/// Configuration of how far a character can reach for a ledge and how it hangs from it.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct LedgeGrabConfig {
+    /// How far in front of the character to look for a wall.
+    pub wall_probe_distance: f32,
+    /// The highest ledge that can be grabbed, relative to the character's center.
+    pub max_reach: f32,
+    /// The lowest ledge that can be grabbed, relative to the character's center.
+    ///
+    /// Anything lower than this is left to step climbing and landing.
+    pub min_reach: f32,
+    /// How far into the wall the downward probe is done to find the top of the ledge.
+    pub ledge_inset: f32,
+    /// How far below the ledge the character's center hangs.
+    pub hang_depth: f32,
+    /// The character won't grab ledges while moving upward faster than this.
+    pub max_upward_speed: f32,
+    /// Speed when shimmying sideways along a ledge.
+    pub shimmy_speed: f32,
+    /// Duration of the timed mantle motion, in seconds. Zero or less mantles instantly.
+    pub mantle_duration: f32,
+    /// Time after letting go of a ledge before another one can be grabbed, in seconds.
+    pub regrab_cooldown: f32,
+}
+
+impl Default for LedgeGrabConfig {
+    fn default() -> Self {
+        Self {
+            wall_probe_distance: 0.3,
+            max_reach: 1.2,
+            min_reach: 0.0,
+            ledge_inset: 0.15,
+            hang_depth: 0.75,
+            max_upward_speed: 2.0,
+            shimmy_speed: 2.0,
+            mantle_duration: 0.4,
+            regrab_cooldown: 0.3,
+        }
+    }
+}
+
+/// Inserted on a character while it's hanging from, or mantling up, a ledge.
+///
+/// The character is in [`LEDGE_GRAB_MODE`] while this component is present.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct LedgeGrab {
+    /// The entity the ledge belongs to.
+    pub entity: Entity,
+    /// The point on top of the ledge that is being held.
+    pub point: Vec3,
+    /// The normal of the top of the ledge.
+    pub normal: Dir3,
+    /// The horizontal normal of the wall below the ledge, pointing towards the character.
+    pub wall_normal: Dir3,
+    /// Where the character ends up after mantling.
+    pub mantle_target: Vec3,
+    pub state: LedgeGrabState,
+}
+
+impl LedgeGrab {
+    /// Start mantling up the ledge from the current `translation`, does nothing if already mantling.
+    pub fn mantle(&mut self, translation: Vec3) {
+        if self.state == LedgeGrabState::Hanging {
+            self.state = LedgeGrabState::Mantling {
+                elapsed: 0.0,
+                start: translation,
+            };
+        }
+    }
+}
+
+#[derive(Reflect, Debug, PartialEq, Clone, Copy)]
+pub enum LedgeGrabState {
+    Hanging,
+    Mantling { elapsed: f32, start: Vec3 },
+}
+
+/// Prevents a character from grabbing a ledge until the remaining time runs out.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct LedgeGrabCooldown(pub f32);
+
+/// A ledge found by [`find_ledge`].
+#[derive(Debug, Clone, Copy)]
+pub struct LedgeHit {
+    pub entity: Entity,
+    pub point: Vec3,
+    pub normal: Dir3,
+    pub wall_normal: Dir3,
+    /// The safe distance the character can move towards the wall.
+    pub wall_distance: f32,
+}
+
+/// Look for a grabbable ledge in front of the character.
+///
+/// # How This Works
+///
+/// 1. **Wall Check**
+///    - Sweep the character forward in the given `direction`
+///    - The hit surface has to be a wall facing the character
+///
+/// 2. **Ledge Discovery**
+///    - Raycast down from `max_reach` above the character, just behind the wall surface
+///    - If the ray starts inside the wall, the wall continues upward and there's no ledge
+///    - The top of the ledge has to be walkable
+///
+/// 3. **Clearance Check**
+///    - Make sure the character fits on top of the ledge
+pub fn find_ledge(
+    spatial_query: &SpatialQuery,
+    collider: &Collider,
+    translation: Vec3,
+    rotation: Quat,
+    up: Dir3,
+    direction: Dir3,
+    config: &LedgeGrabConfig,
+    epsilon: f32,
+    filter: &SpatialQueryFilter,
+) -> Option<LedgeHit> {
+    let (wall_distance, wall_hit) = sweep_check(
+        collider,
+        epsilon,
+        translation,
+        direction,
+        config.wall_probe_distance,
+        rotation,
+        spatial_query,
+        filter,
+    )?;
+
+    // Walkable surfaces are handled by regular movement
+    if is_walkable(wall_hit.normal1, up, EXAMPLE_WALKABLE_ANGLE) {
+        return None;
+    }
+
+    let wall_normal = Dir3::new(wall_hit.normal1.reject_from_normalized(*up)).ok()?;
+
+    // Only grab walls we're actually facing
+    if wall_normal.dot(*direction) > -0.5 {
+        return None;
+    }
+
+    // Probe from above, slightly behind the wall surface
+    let inset_point = wall_hit.point1 - wall_normal * config.ledge_inset;
+    let origin =
+        inset_point - up * inset_point.dot(*up) + up * (translation.dot(*up) + config.max_reach);
+
+    let ray_hit = spatial_query.cast_ray(
+        origin,
+        -up,
+        config.max_reach - config.min_reach,
+        true,
+        filter,
+    )?;
+
+    // The ray started inside the wall, so the wall continues above our reach
+    if ray_hit.distance <= epsilon {
+        return None;
+    }
+
+    let ground =
+        Ground::new_if_walkable(ray_hit.entity, ray_hit.normal, up, EXAMPLE_WALKABLE_ANGLE)?;
+
+    let point = origin - up * ray_hit.distance;
+
+    // Make sure the character fits on top of the ledge
+    let target = mantle_target(point, up, wall_normal, epsilon);
+    if !spatial_query
+        .shape_intersections(collider, target, rotation, filter)
+        .is_empty()
+    {
+        return None;
+    }
+
+    Some(LedgeHit {
+        entity: ground.entity,
+        point,
+        normal: ground.normal,
+        wall_normal,
+        wall_distance,
+    })
+}
+
+/// Where the character stands after mantling up onto the ledge `point`.
+fn mantle_target(point: Vec3, up: Dir3, wall_normal: Dir3, epsilon: f32) -> Vec3 {
+    point + up * (CHARACTER_HALF_HEIGHT + epsilon) - wall_normal * EXAMPLE_CHARACTER_RADIUS
+}
+
+/// Where the character hangs from the ledge `point`, keeping the horizontal position of `translation`.
+fn hang_position(translation: Vec3, point: Vec3, up: Dir3, hang_depth: f32) -> Vec3 {
+    translation + up * ((point - translation).dot(*up) - hang_depth)
+}
+
+fn ledge_detection(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Actions<DefaultContext>,
+            &mut Transform,
+            &mut Character,
+            &mut MovementMode,
+            &Collider,
+            &CharacterFilter,
+            &LedgeGrabConfig,
+        ),
+        (Without<Frozen>, Without<LedgeGrabCooldown>),
+    >,
+    main_camera: Single<&Transform, (With<MainCamera>, Without<Character>)>,
+    one_way_platforms: Query<&GlobalTransform, With<OneWayPlatform>>,
+    spatial_query: SpatialQuery,
+) {
+    let main_camera_transform = main_camera.into_inner();
+    for (entity, actions, mut transform, mut character, mut mode, collider, filter, config) in
+        &mut query
+    {
+        if *mode != MovementMode::Falling
+            || character.grounded()
+            || character.velocity.dot(*character.up) > config.max_upward_speed
+        {
+            continue;
+        }
+
+        let input_vec = actions.action::<input::Move>().value().as_axis2d();
+
+        // Only grab ledges the character is moving towards
+        let camera_yaw = main_camera_transform.rotation.to_euler(EulerRot::YXZ).0;
+        let yaw_rotation = Quat::from_rotation_y(camera_yaw);
+        let Ok(direction) = Dir3::new(yaw_rotation * Vec3::new(input_vec.x, 0.0, -input_vec.y))
+        else {
+            continue;
+        };
+
+        let Some(ledge) = find_ledge(
+            &spatial_query,
+            collider,
+            transform.translation,
+            transform.rotation,
+            character.up,
+            direction,
+            config,
+            character.config.epsilon,
+            &filter.0,
+        ) else {
+            continue;
+        };
+
+        // The sides of one-way platforms don't block, so there's no wall to hang from
+        if passes_one_way(
+            &one_way_platforms,
+            ledge.entity,
+            *ledge.wall_normal,
+            character.velocity,
+        ) {
+            continue;
+        }
+
+        let hang = hang_position(
+            transform.translation + direction * ledge.wall_distance,
+            ledge.point,
+            character.up,
+            config.hang_depth,
+        );
+
+        // Can't hang here, something is in the way
+        if !spatial_query
+            .shape_intersections(collider, hang, transform.rotation, &filter.0)
+            .is_empty()
+        {
+            continue;
+        }
+
+        transform.translation = hang;
+        character.velocity = Vec3::ZERO;
+        *mode = LEDGE_GRAB_MODE;
+
+        commands.entity(entity).insert(LedgeGrab {
+            entity: ledge.entity,
+            point: ledge.point,
+            normal: ledge.normal,
+            wall_normal: ledge.wall_normal,
+            mantle_target: mantle_target(
+                ledge.point,
+                character.up,
+                ledge.wall_normal,
+                character.config.epsilon,
+            ),
+            state: LedgeGrabState::Hanging,
+        });
+    }
+}
+
+fn ledge_grab_update(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Actions<DefaultContext>,
+            &mut Transform,
+            &mut Character,
+            &mut MovementMode,
+            &mut LedgeGrab,
+            &Collider,
+            &CharacterFilter,
+            &LedgeGrabConfig,
+        ),
+        Without<Frozen>,
+    >,
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+) {
+    for (
+        entity,
+        actions,
+        mut transform,
+        mut character,
+        mut mode,
+        mut grab,
+        collider,
+        filter,
+        config,
+    ) in &mut query
+    {
+        if *mode != LEDGE_GRAB_MODE {
+            continue;
+        }
+
+        // Hanging characters don't move on their own
+        character.velocity = Vec3::ZERO;
+
+        match grab.state {
+            LedgeGrabState::Hanging => {
+                let input_vec = actions.action::<input::Move>().value().as_axis2d();
+
+                // Pull away from the wall to let go
+                if input_vec.y < -0.5 {
+                    *mode = MovementMode::Falling;
+                    commands
+                        .entity(entity)
+                        .insert(LedgeGrabCooldown(config.regrab_cooldown));
+                    continue;
+                }
+
+                // Push into the wall to mantle up
+                if input_vec.y > 0.5 {
+                    grab.mantle(transform.translation);
+                    continue;
+                }
+
+                // Shimmy sideways along the ledge
+                let tangent = grab.wall_normal.cross(*character.up);
+                let Ok((direction, max_distance)) = Dir3::new_and_length(
+                    tangent * input_vec.x * config.shimmy_speed * time.delta_secs(),
+                ) else {
+                    continue;
+                };
+
+                let safe_distance = sweep_check(
+                    collider,
+                    character.config.epsilon,
+                    transform.translation,
+                    direction,
+                    max_distance,
+                    transform.rotation,
+                    &spatial_query,
+                    &filter.0,
+                )
+                .map(|(d, _)| d)
+                .unwrap_or(max_distance);
+
+                let translation = transform.translation + direction * safe_distance;
+
+                // Only move if the ledge continues at the new position
+                let Some(ledge) = find_ledge(
+                    &spatial_query,
+                    collider,
+                    translation,
+                    transform.rotation,
+                    character.up,
+                    -grab.wall_normal,
+                    config,
+                    character.config.epsilon,
+                    &filter.0,
+                ) else {
+                    continue;
+                };
+
+                transform.translation = hang_position(
+                    translation - grab.wall_normal * ledge.wall_distance,
+                    ledge.point,
+                    character.up,
+                    config.hang_depth,
+                );
+
+                *grab = LedgeGrab {
+                    entity: ledge.entity,
+                    point: ledge.point,
+                    normal: ledge.normal,
+                    wall_normal: ledge.wall_normal,
+                    mantle_target: mantle_target(
+                        ledge.point,
+                        character.up,
+                        ledge.wall_normal,
+                        character.config.epsilon,
+                    ),
+                    state: LedgeGrabState::Hanging,
+                };
+            }
+            LedgeGrabState::Mantling { elapsed, start } => {
+                let elapsed = elapsed + time.delta_secs();
+                // Mantle instantly without a duration
+                let t = match config.mantle_duration > 0.0 {
+                    true => (elapsed / config.mantle_duration).min(1.0),
+                    false => 1.0,
+                };
+
+                // Move up first, then forward onto the ledge
+                let top = start + character.up * (grab.mantle_target - start).dot(*character.up);
+                transform.translation = match t < 0.6 {
+                    true => start.lerp(top, t / 0.6),
+                    false => top.lerp(grab.mantle_target, (t - 0.6) / 0.4),
+                };
+
+                if t < 1.0 {
+                    grab.state = LedgeGrabState::Mantling { elapsed, start };
+                    continue;
+                }
+
+                // Finished mantling, we're standing on the ledge
+                character.ground = Some(Ground {
+                    entity: grab.entity,
+                    normal: grab.normal,
+                });
+
+                *mode = MovementMode::Walking;
+            }
+        }
+    }
+}
+
+fn tick_ledge_grab_cooldown(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut LedgeGrabCooldown)>,
+    time: Res<Time>,
+) {
+    for (entity, mut cooldown) in &mut query {
+        cooldown.0 -= time.delta_secs();
+
+        if cooldown.0 <= 0.0 {
+            commands.entity(entity).remove::<LedgeGrabCooldown>();
+        }
+    }
+}
+
+fn ledge_jump_input(
+    mut query: Query<(
+        &mut LedgeGrab,
+        &MovementMode,
+        &Transform,
+        &Actions<DefaultContext>,
+    )>,
+) {
+    for (mut grab, mode, transform, actions) in &mut query {
+        if *mode == LEDGE_GRAB_MODE && actions.action::<Jump>().state() == ActionState::Fired {
+            grab.mantle(transform.translation);
+        }
+    }
+}
+
+fn exit_ledge_grab(trigger: Trigger<ExitMovementMode>, mut commands: Commands) {
+    if trigger.0 == LEDGE_GRAB_MODE {
+        commands.entity(trigger.target()).remove::<LedgeGrab>();
+    }
+}
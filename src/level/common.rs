@@ -1,4 +1,4 @@
-use avian3d::prelude::{Collider, RigidBody};
+use avian3d::prelude::{Collider, CollisionLayers, RigidBody};
 use bevy::{math::Affine2, prelude::*};
 use std::collections::HashMap;
 
@@ -107,6 +107,7 @@ pub fn spawn_static_cuboid(
     size: Vec3,
     transform: Transform,
     texture_index: usize,
+    layers: CollisionLayers,
 ) -> Entity {
     if size.x <= 0.0 || size.y <= 0.0 || size.z <= 0.0 {
         error!(
@@ -123,6 +124,7 @@ pub fn spawn_static_cuboid(
             transform,
             RigidBody::Static,
             Collider::cuboid(size.x, size.y, size.z),
+            layers,
             Geometry,
             Name::new(name),
         ))
@@ -139,6 +141,7 @@ pub fn spawn_kinematic_cuboid(
     size: Vec3,
     transform: Transform,
     texture_index: usize,
+    layers: CollisionLayers,
 ) -> Entity {
     if size.x <= 0.0 || size.y <= 0.0 || size.z <= 0.0 {
         error!(
@@ -155,6 +158,7 @@ pub fn spawn_kinematic_cuboid(
             transform,
             RigidBody::Kinematic, // Key difference
             Collider::cuboid(size.x, size.y, size.z),
+            layers,
             Geometry,
             Name::new(name.clone()), // Clone name for Name component
         ))
@@ -172,6 +176,7 @@ pub fn spawn_static_shape(
     transform: Transform,
     texture_index: usize,
     bounding_box_size: Vec3, // Provide approx bounding box for UV scaling
+    layers: CollisionLayers,
 ) -> Entity {
     let material =
         create_material_with_uv_approx(texture_index, bounding_box_size, level_assets, materials);
@@ -183,6 +188,7 @@ pub fn spawn_static_shape(
             transform,
             RigidBody::Static,
             collider, // Use the provided collider
+            layers,
             Geometry,
             Name::new(name),
         ))
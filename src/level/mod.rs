@@ -1,4 +1,5 @@
 pub mod common;
+pub mod platform_path;
 pub mod tracks;
 pub mod utils;
 
@@ -39,10 +40,19 @@ impl Plugin for LevelGeneratorPlugin {
                 ShapeObstaclesTrackPlugin,
                 CapsuleForestTrackPlugin,
                 CylinderBridgeTrackPlugin,
+                ConveyorsTrackPlugin,
                 // Add other track plugins here:
                 // WallsTrackPlugin,
                 // CeilingsTrackPlugin,
             ))
+            // Tracks for testing character abilities
+            .add_plugins((
+                WaterTrackPlugin,
+                LaddersTrackPlugin,
+                OneWayPlatformsTrackPlugin,
+            ))
+            // Moving platforms
+            .add_plugins(platform_path::plugin)
             // --- General Setup ---
             .insert_resource(AmbientLight {
                 brightness: 700.0, // Adjust brightness as needed
@@ -0,0 +1,207 @@
+use std::f32::consts::TAU;
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+
+use crate::movement_mode::MovementSet;
+
+pub(crate) fn plugin(app: &mut App) {
+    app.add_systems(
+        FixedUpdate,
+        (
+            (follow_platform_path, follow_platform_rotation),
+            tick_platform_time,
+        )
+            .chain()
+            .before(MovementSet::Update),
+    );
+}
+
+/// Time since a moving platform started moving, advanced in the fixed schedule.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct PlatformTime(pub f32);
+
+/// Moves a kinematic platform along a path by setting its [`LinearVelocity`] every fixed tick.
+///
+/// Points are in world space.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+#[require(PlatformTime)]
+pub enum PlatformPath {
+    /// Travel along the waypoints and back again, stopping at each waypoint.
+    PingPong {
+        waypoints: Vec<Vec3>,
+        /// Time to travel between two waypoints, in seconds.
+        segment_duration: f32,
+        /// Time to wait at each waypoint, in seconds.
+        pause: f32,
+        /// Easing of the motion between two waypoints.
+        ease: EaseFunction,
+    },
+    /// Travel along a closed Catmull-Rom spline, built once with [`PlatformPath::looping`].
+    Loop {
+        curve: CubicCurve<Vec3>,
+        /// Time to complete one loop, in seconds.
+        duration: f32,
+    },
+}
+
+impl PlatformPath {
+    /// Travel along a closed Catmull-Rom spline through the `points`, completing one loop in `duration` seconds.
+    ///
+    /// Returns `None` if there are not enough points to build the spline.
+    pub fn looping(points: impl IntoIterator<Item = Vec3>, duration: f32) -> Option<Self> {
+        let curve = CubicCardinalSpline::new_catmull_rom(points)
+            .to_curve_cyclic()
+            .ok()?;
+
+        Some(PlatformPath::Loop { curve, duration })
+    }
+
+    /// Returns the position along the path at time `t`.
+    pub fn position(&self, t: f32) -> Option<Vec3> {
+        match self {
+            PlatformPath::PingPong {
+                waypoints,
+                segment_duration,
+                pause,
+                ease,
+            } => {
+                let last = waypoints.len().checked_sub(1).filter(|last| *last > 0)?;
+                let leg_duration = segment_duration + pause;
+                if leg_duration <= 0.0 {
+                    return waypoints.first().copied();
+                }
+
+                // Forward through all waypoints, then back again
+                let legs = 2 * last;
+                let time = t.rem_euclid(leg_duration * legs as f32);
+                let leg = ((time / leg_duration) as usize).min(legs - 1);
+                let (from, to) = match leg < last {
+                    true => (leg, leg + 1),
+                    false => (legs - leg, legs - leg - 1),
+                };
+
+                // Wait at the start of each leg
+                let local = time - leg as f32 * leg_duration - pause;
+                if local <= 0.0 || *segment_duration <= 0.0 {
+                    return Some(waypoints[from]);
+                }
+
+                let s = ease.sample_clamped(local / segment_duration);
+                Some(waypoints[from].lerp(waypoints[to], s))
+            }
+            PlatformPath::Loop { curve, duration } => {
+                if *duration <= 0.0 {
+                    return Some(curve.position(0.0));
+                }
+
+                let segments = curve.segments().len() as f32;
+
+                Some(curve.position(t.rem_euclid(*duration) / duration * segments))
+            }
+        }
+    }
+}
+
+/// Rotates a kinematic platform around an axis by setting its [`AngularVelocity`] every fixed tick.
+///
+/// The rotation is relative to an unrotated platform.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+#[require(PlatformTime)]
+pub struct PlatformRotation {
+    pub axis: Dir3,
+    /// Time to complete a full revolution, not counting pauses, in seconds.
+    pub period: f32,
+    /// Number of evenly spaced stops per revolution, `0` rotates continuously.
+    pub stops: u32,
+    /// Time to wait at each stop, in seconds.
+    pub pause: f32,
+}
+
+impl PlatformRotation {
+    /// Continuous rotation around `axis`.
+    pub fn continuous(axis: Dir3, period: f32) -> Self {
+        Self {
+            axis,
+            period,
+            stops: 0,
+            pause: 0.0,
+        }
+    }
+
+    /// Returns the rotation at time `t`.
+    pub fn rotation(&self, t: f32) -> Quat {
+        if self.period <= 0.0 {
+            return Quat::IDENTITY;
+        }
+
+        let angle = match self.stops {
+            0 => t / self.period * TAU,
+            stops => {
+                let step_duration = self.period / stops as f32;
+                let cycle = step_duration + self.pause;
+                let step = (t / cycle).floor();
+                let local = (t - step * cycle - self.pause).max(0.0);
+
+                (step + local / step_duration) * TAU / stops as f32
+            }
+        };
+
+        Quat::from_axis_angle(*self.axis, angle.rem_euclid(TAU))
+    }
+}
+
+fn follow_platform_path(
+    mut query: Query<(&PlatformPath, &PlatformTime, &Position, &mut LinearVelocity)>,
+    time: Res<Time>,
+) {
+    let delta = time.delta_secs();
+    if delta <= 0.0 {
+        return;
+    }
+
+    for (path, platform_time, position, mut velocity) in &mut query {
+        // Reach the position at the end of this tick
+        let Some(target) = path.position(platform_time.0 + delta) else {
+            continue;
+        };
+
+        velocity.0 = (target - position.0) / delta;
+    }
+}
+
+fn follow_platform_rotation(
+    mut query: Query<(
+        &PlatformRotation,
+        &PlatformTime,
+        &Rotation,
+        &mut AngularVelocity,
+    )>,
+    time: Res<Time>,
+) {
+    let delta = time.delta_secs();
+    if delta <= 0.0 {
+        return;
+    }
+
+    for (platform_rotation, platform_time, rotation, mut velocity) in &mut query {
+        let target = platform_rotation.rotation(platform_time.0 + delta);
+
+        // Take the shortest way to the target rotation
+        let mut difference = target * rotation.0.inverse();
+        if difference.w < 0.0 {
+            difference = -difference;
+        }
+
+        velocity.0 = difference.to_scaled_axis() / delta;
+    }
+}
+
+fn tick_platform_time(mut query: Query<&mut PlatformTime>, time: Res<Time>) {
+    for mut platform_time in &mut query {
+        platform_time.0 += time.delta_secs();
+    }
+}
@@ -1,6 +1,9 @@
-use crate::level::{
-    common::{self, Param},
-    utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+use crate::{
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+    },
 };
 use bevy::prelude::*;
 use std::collections::HashMap;
@@ -46,8 +49,8 @@ const PARAMS: &[(&str, Param)] = &[
         },
     ), // Widths: 1.0, 2.0
 ];
-// Length of the corridor walls along Z
-const CORRIDOR_LENGTH: f32 = 7.5;
+// Length of the corridor walls along Z, long enough to test wall running
+const CORRIDOR_LENGTH: f32 = 15.0;
 
 // --- Setup System ---
 fn setup_angled_walls_track(
@@ -150,6 +153,7 @@ fn spawn_angled_corridor_instance(
         wall_size,
         transform_left,
         texture_index,
+        GameLayer::World.layers(),
     );
 
     // Spawn Right Wall
@@ -166,5 +170,6 @@ fn spawn_angled_corridor_instance(
         wall_size,
         transform_right,
         texture_index,
+        GameLayer::World.layers(),
     );
 }
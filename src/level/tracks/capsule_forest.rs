@@ -1,6 +1,9 @@
-use crate::level::{
-    common::{self, Param},
-    utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+use crate::{
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+    },
 };
 use avian3d::prelude::Collider;
 use bevy::prelude::*;
@@ -161,6 +164,7 @@ fn spawn_capsule_forest_instance(
             transform,
             texture_index,
             bbox,
+            GameLayer::World.layers(),
         );
         commands.entity(parent_entity).add_child(capsule_entity);
     }
@@ -0,0 +1,159 @@
+use crate::{
+    character::SurfaceVelocity,
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, TextureAssets, TrackOffsets},
+    },
+};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+// --- Plugin Definition ---
+pub struct ConveyorsTrackPlugin;
+
+impl Plugin for ConveyorsTrackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Startup,
+            setup_conveyors_track.after(super::super::load_assets_and_setup),
+        );
+    }
+}
+
+// --- Constants ---
+const TRACK_NAME: &str = "Conveyors";
+const TRACK_Z: f32 = 140.0; // Place this track
+const TEX_BELT: usize = 5 * 13 + 2; // Example texture
+const BELT_WIDTH: f32 = 2.5;
+const BELT_LENGTH: f32 = 8.0;
+const BELT_THICKNESS: f32 = 0.2;
+
+// --- Parameter Ranges ---
+// 3 * 3 * 2 = 18 instances
+const PARAMS: &[(&str, Param)] = &[
+    // Speed of the belt surface
+    (
+        "speed",
+        Param::Float {
+            start: 2.0,
+            end: 6.0,
+            step: 2.0,
+        },
+    ), // Speeds: 2.0, 4.0, 6.0
+    // Incline of the belt in degrees, the far end is raised
+    (
+        "incline",
+        Param::Float {
+            start: 0.0,
+            end: 20.0,
+            step: 10.0,
+        },
+    ), // Inclines: 0, 10, 20
+    // 0: belt moves away from the track (uphill), 1: belt moves towards the track (downhill)
+    (
+        "reverse",
+        Param::Int {
+            start: 0,
+            end: 1,
+            step: 1,
+        },
+    ),
+];
+
+// --- Setup System ---
+fn setup_conveyors_track(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut track_offsets: ResMut<TrackOffsets>,
+    level_assets: Res<TextureAssets>,
+    mut animation_clips: ResMut<Assets<AnimationClip>>, // Needed for signature
+    mut animation_graphs: ResMut<Assets<AnimationGraph>>,
+) {
+    info!("Generating track: {}", TRACK_NAME);
+
+    let generator_closure =
+        |permutation: &HashMap<String, f64>,
+         cmds: &mut Commands,
+         mshs: &mut ResMut<Assets<Mesh>>,
+         mats: &mut ResMut<Assets<StandardMaterial>>,
+         offsets: &mut ResMut<TrackOffsets>,
+         assets: &Res<TextureAssets>,
+         _clips: &mut ResMut<Assets<AnimationClip>>,
+         _graphs: &mut ResMut<Assets<AnimationGraph>>| {
+            let speed = permutation["speed"] as f32;
+            let incline = permutation["incline"] as f32;
+            let reverse = permutation["reverse"] as i32 == 1;
+
+            let velocity = match reverse {
+                true => -speed,
+                false => speed,
+            };
+
+            let name = format!("Conveyor_v{:.1}_i{:.0}", velocity, incline);
+
+            spawn_conveyor_instance(cmds, mshs, mats, offsets, assets, &name, velocity, incline);
+        };
+
+    common::generate_permutations(
+        PARAMS,
+        generator_closure,
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut track_offsets,
+        &level_assets,
+        &mut animation_clips,
+        &mut animation_graphs,
+    );
+}
+
+/// Spawns a single conveyor belt, `velocity` is the speed of the surface along the belt, positive moving up the incline.
+fn spawn_conveyor_instance(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    track_offsets: &mut ResMut<TrackOffsets>,
+    level_assets: &Res<TextureAssets>,
+    name: &str,
+    velocity: f32,
+    incline_degrees: f32,
+) {
+    let section_center_x = track_offsets.get_and_advance(TRACK_NAME, BELT_WIDTH);
+
+    if !(0.0..90.0).contains(&incline_degrees) {
+        warn!("Skipping conveyor '{}': invalid incline.", name);
+        return;
+    }
+
+    let parent_entity = commands
+        .spawn((
+            Transform::from_xyz(section_center_x, BASE_Y, TRACK_Z),
+            Name::new(name.to_string()),
+        ))
+        .id();
+
+    // --- Belt, starting at the ground and rising along +Z ---
+    let incline_rad = incline_degrees.to_radians();
+    let belt_center_y =
+        (BELT_LENGTH / 2.0) * incline_rad.sin() + (BELT_THICKNESS / 2.0) * incline_rad.cos();
+    let belt_center_z = (BELT_LENGTH / 2.0) * incline_rad.cos();
+
+    let belt_entity = common::spawn_static_cuboid(
+        commands,
+        meshes,
+        materials,
+        level_assets,
+        format!("{}_Belt", name),
+        Vec3::new(BELT_WIDTH, BELT_THICKNESS, BELT_LENGTH),
+        Transform::from_xyz(0.0, belt_center_y, belt_center_z) // Relative to parent
+            .with_rotation(Quat::from_rotation_x(-incline_rad)),
+        TEX_BELT,
+        GameLayer::World.layers(),
+    );
+    commands
+        .entity(belt_entity)
+        .insert(SurfaceVelocity(Vec3::Z * velocity)); // Along the length of the belt
+    commands.entity(parent_entity).add_child(belt_entity);
+}
@@ -1,6 +1,9 @@
-use crate::level::{
-    common::{self, Param},
-    utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+use crate::{
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+    },
 };
 use bevy::prelude::*;
 use std::collections::HashMap;
@@ -165,6 +168,7 @@ fn spawn_crevice_instance(
         wall_size,
         transform_left,
         texture_index,
+        GameLayer::World.layers(),
     );
 
     // Spawn Right Wall
@@ -184,5 +188,6 @@ fn spawn_crevice_instance(
         wall_size,
         transform_right,
         texture_index,
+        GameLayer::World.layers(),
     );
 }
@@ -1,6 +1,9 @@
-use crate::level::{
-    common::{self, Param},
-    utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+use crate::{
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+    },
 };
 use avian3d::prelude::Collider;
 use bevy::prelude::*;
@@ -170,5 +173,6 @@ fn spawn_cylinder_bridge_instance(
         transform,
         texture_index,
         bbox,
+        GameLayer::World.layers(),
     );
 }
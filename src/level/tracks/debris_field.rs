@@ -1,6 +1,9 @@
-use crate::level::{
-    common::{self, Param},
-    utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+use crate::{
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+    },
 };
 use bevy::prelude::*;
 use core::f32;
@@ -177,6 +180,7 @@ fn spawn_debris_field_instance(
             debris_size,
             transform,
             texture_index,
+            GameLayer::World.layers(),
         );
         commands.entity(parent_entity).add_child(debris_entity);
     }
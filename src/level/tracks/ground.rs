@@ -1,9 +1,12 @@
 // src/level/plugins/ground.rs
 
-use crate::level::{
-    common,
-    load_assets_and_setup,
-    utils::{BASE_Y, TextureAssets}, // Use resources and constants
+use crate::{
+    layers::GameLayer,
+    level::{
+        common,
+        load_assets_and_setup,
+        utils::{BASE_Y, TextureAssets}, // Use resources and constants
+    },
 };
 use bevy::prelude::*;
 
@@ -38,5 +41,6 @@ fn setup_ground(
         GROUND_SIZE,
         Transform::from_translation(GROUND_POS),
         TEX_GROUND,
+        GameLayer::World.layers(),
     );
 }
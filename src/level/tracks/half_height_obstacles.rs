@@ -1,7 +1,11 @@
-use crate::level::{
-    common::{self, Param},
-    utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+use crate::{
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+    },
 };
+use avian3d::prelude::CollisionLayers;
 use bevy::prelude::*;
 use std::collections::HashMap;
 
@@ -22,19 +26,20 @@ const TRACK_NAME: &str = "HalfHeightObstacles";
 const TRACK_Z: f32 = 100.0; // Place this track far forward
 const TEX_OBSTACLE: usize = 4 * 13; // Example texture (wall?)
 const OBSTACLE_THICKNESS: f32 = 0.2; // Keep thickness constant
+const TEX_CAMERA_BLOCKER: usize = 5 * 13 + 5; // Walls the character walks through but the camera doesn't
 
 // --- Parameter Ranges ---
-// 2 * 2 = 4 instances
+// 5 * 2 = 10 instances
 const PARAMS: &[(&str, Param)] = &[
-    // Height of the obstacle (should require crouching for typical characters)
+    // Height of the obstacle, the taller ones are meant for testing ledge grabbing
     (
         "height",
         Param::Float {
             start: 0.8,
-            end: 1.2,
-            step: 0.4,
+            end: 2.8,
+            step: 0.5,
         },
-    ), // Heights: 0.8, 1.2
+    ), // Heights: 0.8, 1.3, 1.8, 2.3, 2.8
     // Width of the obstacle wall
     (
         "width",
@@ -82,6 +87,7 @@ fn setup_half_height_obstacles_track(
                 height,
                 width,
                 TEX_OBSTACLE,
+                GameLayer::World.layers(),
             );
         };
 
@@ -96,6 +102,20 @@ fn setup_half_height_obstacles_track(
         &mut animation_clips,
         &mut animation_graphs,
     );
+
+    // Blocks the camera but not the character, walk through it to test the spring arm
+    spawn_half_obstacle_instance(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut track_offsets,
+        &level_assets,
+        "CameraBlocker_h2.8_w5.0",
+        2.8,
+        5.0,
+        TEX_CAMERA_BLOCKER,
+        GameLayer::CameraBlocker.layers(),
+    );
 }
 
 /// Spawns a single half-height obstacle instance.
@@ -109,6 +129,7 @@ fn spawn_half_obstacle_instance(
     height: f32,
     width: f32,
     texture_index: usize,
+    layers: CollisionLayers,
 ) {
     // Footprint is the width of the obstacle
     let section_center_x = track_offsets.get_and_advance(TRACK_NAME, width);
@@ -134,5 +155,6 @@ fn spawn_half_obstacle_instance(
         obstacle_size,
         Transform::from_translation(obstacle_pos),
         texture_index,
+        layers,
     );
 }
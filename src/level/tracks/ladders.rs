@@ -0,0 +1,157 @@
+use crate::{
+    climbing::Climbable,
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, TextureAssets, TrackOffsets},
+    },
+};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+// --- Plugin Definition ---
+pub struct LaddersTrackPlugin;
+
+impl Plugin for LaddersTrackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Startup,
+            setup_ladders_track.after(super::super::load_assets_and_setup),
+        );
+    }
+}
+
+// --- Constants ---
+const TRACK_NAME: &str = "Ladders";
+const TRACK_Z: f32 = -160.0; // Place this track
+const TEX_TOWER: usize = 2 * 13 + 1; // Example texture
+const TEX_LADDER: usize = 4 * 13 + 5;
+const TOWER_SIZE: f32 = 3.0; // Width and depth of the tower the ladder leads up to
+const LADDER_WIDTH: f32 = 1.0;
+const LADDER_THICKNESS: f32 = 0.1;
+
+// --- Parameter Ranges ---
+// 3 * 2 = 6 instances
+const PARAMS: &[(&str, Param)] = &[
+    // Height of the tower, and the top of the ladder
+    (
+        "height",
+        Param::Float {
+            start: 2.0,
+            end: 6.0,
+            step: 2.0,
+        },
+    ), // Heights: 2.0, 4.0, 6.0
+    // Angle (degrees) the ladder leans away from vertical
+    (
+        "tilt",
+        Param::Float {
+            start: 0.0,
+            end: 20.0,
+            step: 20.0,
+        },
+    ), // Tilts: 0, 20
+];
+
+// --- Setup System ---
+fn setup_ladders_track(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut track_offsets: ResMut<TrackOffsets>,
+    level_assets: Res<TextureAssets>,
+    mut animation_clips: ResMut<Assets<AnimationClip>>, // Needed for signature
+    mut animation_graphs: ResMut<Assets<AnimationGraph>>,
+) {
+    info!("Generating track: {}", TRACK_NAME);
+
+    let generator_closure =
+        |permutation: &HashMap<String, f64>,
+         cmds: &mut Commands,
+         mshs: &mut ResMut<Assets<Mesh>>,
+         mats: &mut ResMut<Assets<StandardMaterial>>,
+         offsets: &mut ResMut<TrackOffsets>,
+         assets: &Res<TextureAssets>,
+         _clips: &mut ResMut<Assets<AnimationClip>>,
+         _graphs: &mut ResMut<Assets<AnimationGraph>>| {
+            let height = permutation["height"] as f32;
+            let tilt = permutation["tilt"] as f32;
+
+            let name = format!("Ladder_h{:.1}_t{:.0}", height, tilt);
+
+            spawn_ladder_instance(cmds, mshs, mats, offsets, assets, &name, height, tilt);
+        };
+
+    common::generate_permutations(
+        PARAMS,
+        generator_closure,
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut track_offsets,
+        &level_assets,
+        &mut animation_clips,
+        &mut animation_graphs,
+    );
+}
+
+/// Spawns a single tower with a ladder leaning against its front face.
+fn spawn_ladder_instance(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    track_offsets: &mut ResMut<TrackOffsets>,
+    level_assets: &Res<TextureAssets>,
+    name: &str,
+    height: f32,
+    tilt_degrees: f32,
+) {
+    let section_center_x = track_offsets.get_and_advance(TRACK_NAME, TOWER_SIZE);
+
+    if height <= 0.0 || !(0.0..90.0).contains(&tilt_degrees) {
+        warn!("Skipping ladder '{}': invalid height/tilt.", name);
+        return;
+    }
+
+    let parent_entity = commands
+        .spawn((
+            Transform::from_xyz(section_center_x, BASE_Y, TRACK_Z),
+            Name::new(name.to_string()),
+        ))
+        .id();
+
+    // --- Tower ---
+    let tower_entity = common::spawn_static_cuboid(
+        commands,
+        meshes,
+        materials,
+        level_assets,
+        format!("{}_Tower", name),
+        Vec3::new(TOWER_SIZE, height, TOWER_SIZE),
+        Transform::from_xyz(0.0, height / 2.0, 0.0), // Relative to parent
+        TEX_TOWER,
+        GameLayer::World.layers(),
+    );
+    commands.entity(parent_entity).add_child(tower_entity);
+
+    // --- Ladder, top resting against the front face of the tower ---
+    let tilt_rad = tilt_degrees.to_radians();
+    let ladder_length = height / tilt_rad.cos();
+    let ladder_center_z =
+        TOWER_SIZE / 2.0 + LADDER_THICKNESS / 2.0 + (ladder_length / 2.0) * tilt_rad.sin();
+
+    let ladder_entity = common::spawn_static_cuboid(
+        commands,
+        meshes,
+        materials,
+        level_assets,
+        format!("{}_Ladder", name),
+        Vec3::new(LADDER_WIDTH, ladder_length, LADDER_THICKNESS),
+        Transform::from_xyz(0.0, height / 2.0, ladder_center_z) // Relative to parent
+            .with_rotation(Quat::from_rotation_x(-tilt_rad)),
+        TEX_LADDER,
+        GameLayer::World.layers(),
+    );
+    commands.entity(ladder_entity).insert(Climbable);
+    commands.entity(parent_entity).add_child(ladder_entity);
+}
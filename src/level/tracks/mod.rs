@@ -2,31 +2,39 @@
 
 pub mod angled_walls;
 pub mod capsule_forest;
+pub mod conveyors;
 pub mod crevices;
 pub mod cylinder_bridge;
 pub mod debris_field;
 pub mod ground;
 pub mod half_height_obstacles;
+pub mod ladders;
 pub mod moving_platforms;
 pub mod narrow_beams;
+pub mod one_way_platforms;
 pub mod ramps;
 pub mod ridges;
 pub mod shape_obstacles;
 pub mod stairs;
 pub mod uneven_patches;
+pub mod water;
 
 // Re-export the plugins for easier use in level/mod.rs
 pub use angled_walls::AngledWallsTrackPlugin;
 pub use capsule_forest::CapsuleForestTrackPlugin;
+pub use conveyors::ConveyorsTrackPlugin;
 pub use crevices::CrevicesTrackPlugin;
 pub use cylinder_bridge::CylinderBridgeTrackPlugin;
 pub use debris_field::DebrisFieldTrackPlugin;
 pub use ground::GroundPlugin;
 pub use half_height_obstacles::HalfHeightObstaclesTrackPlugin;
+pub use ladders::LaddersTrackPlugin;
 pub use moving_platforms::MovingPlatformsTrackPlugin;
 pub use narrow_beams::NarrowBeamsTrackPlugin;
+pub use one_way_platforms::OneWayPlatformsTrackPlugin;
 pub use ramps::RampsTrackPlugin;
 pub use ridges::RidgesTrackPlugin;
 pub use shape_obstacles::ShapeObstaclesTrackPlugin;
 pub use stairs::StairsTrackPlugin;
 pub use uneven_patches::UnevenPatchesTrackPlugin;
+pub use water::WaterTrackPlugin;
@@ -1,13 +1,13 @@
-use crate::level::{
-    common::{self, Param, spawn_static_cuboid},
-    utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
-};
-use avian3d::prelude::{Collider, RigidBody};
-use bevy::{
-    animation::{AnimationTarget, AnimationTargetId, animated_field},
-    prelude::*,
+use crate::{
+    layers::GameLayer,
+    level::{
+        common::{self, Param, spawn_static_cuboid},
+        platform_path::{PlatformPath, PlatformRotation},
+        utils::{BASE_Y, TextureAssets, TrackOffsets},
+    },
 };
-use std::{collections::HashMap, f32::consts::PI};
+use bevy::prelude::*;
+use std::collections::HashMap;
 
 // --- Plugin Definition ---
 pub struct MovingPlatformsTrackPlugin;
@@ -26,6 +26,7 @@ const TRACK_NAME: &str = "MovingPlatforms";
 const TRACK_Z: f32 = 20.0;
 const TEX_PLATFORM: usize = 4 * 13 + 3;
 const TEX_OBSTACLE_WALL: usize = 3 * 13;
+const STOP_PAUSE: f32 = 0.5; // Time platforms wait at each end of their path
 
 // --- Parameter Ranges (Simplified for fewer instances by default) ---
 // Vertical (1 instance)
@@ -70,7 +71,7 @@ const H_PARAMS: &[(&str, Param)] = &[
 ];
 const H_PLAT_SIZE: Vec3 = Vec3::new(4.0, 0.3, 2.5);
 
-// Rotating (1 instance, uses UnevenSampleAutoCurve for full 360 loop)
+// Rotating (1 instance, full 360 loop)
 const R_PARAMS: &[(&str, Param)] = &[(
     "r_cycle_dur",
     Param::Float {
@@ -81,6 +82,28 @@ const R_PARAMS: &[(&str, Param)] = &[(
 )];
 const R_PLAT_SIZE: Vec3 = Vec3::new(3.5, 0.3, 3.5);
 
+// Looping (L) Platform following a closed spline (1 instance)
+const L_PARAMS: &[(&str, Param)] = &[
+    (
+        "l_radius",
+        Param::Float {
+            start: 4.0,
+            end: 4.0,
+            step: 1.0,
+        },
+    ),
+    (
+        "l_dur",
+        Param::Float {
+            start: 8.0,
+            end: 8.0,
+            step: 1.0,
+        },
+    ),
+];
+const L_PLAT_SIZE: Vec3 = Vec3::new(3.0, 0.3, 3.0);
+const L_RISE: f32 = 2.0; // Height difference between the low and high points of the loop
+
 // Translate & Rotate (TR) Platform (1 instance)
 const TR_PARAMS: &[(&str, Param)] = &[
     (
@@ -152,8 +175,8 @@ fn setup_moving_platforms_track(
          mats: &mut ResMut<Assets<StandardMaterial>>,
          offsets: &mut ResMut<TrackOffsets>,
          assets: &Res<TextureAssets>,
-         clips: &mut ResMut<Assets<AnimationClip>>,
-         graphs: &mut ResMut<Assets<AnimationGraph>>| {
+         _clips: &mut ResMut<Assets<AnimationClip>>,
+         _graphs: &mut ResMut<Assets<AnimationGraph>>| {
             let v_dist = permutation["v_dist"] as f32;
             let v_dur = permutation["v_dur"] as f32;
             let name = format!("VPlatform_d{:.1}_t{:.1}", v_dist, v_dur);
@@ -163,8 +186,6 @@ fn setup_moving_platforms_track(
                 mats,
                 offsets,
                 assets,
-                clips,
-                graphs,
                 &name,
                 V_PLAT_SIZE,
                 v_dist,
@@ -192,8 +213,8 @@ fn setup_moving_platforms_track(
          mats: &mut ResMut<Assets<StandardMaterial>>,
          offsets: &mut ResMut<TrackOffsets>,
          assets: &Res<TextureAssets>,
-         clips: &mut ResMut<Assets<AnimationClip>>,
-         graphs: &mut ResMut<Assets<AnimationGraph>>| {
+         _clips: &mut ResMut<Assets<AnimationClip>>,
+         _graphs: &mut ResMut<Assets<AnimationGraph>>| {
             let h_dist = permutation["h_dist"] as f32;
             let h_dur = permutation["h_dur"] as f32;
             let name = format!("HPlatform_d{:.1}_t{:.1}", h_dist, h_dur);
@@ -203,8 +224,6 @@ fn setup_moving_platforms_track(
                 mats,
                 offsets,
                 assets,
-                clips,
-                graphs,
                 &name,
                 H_PLAT_SIZE,
                 h_dist,
@@ -232,8 +251,8 @@ fn setup_moving_platforms_track(
          mats: &mut ResMut<Assets<StandardMaterial>>,
          offsets: &mut ResMut<TrackOffsets>,
          assets: &Res<TextureAssets>,
-         clips: &mut ResMut<Assets<AnimationClip>>,
-         graphs: &mut ResMut<Assets<AnimationGraph>>| {
+         _clips: &mut ResMut<Assets<AnimationClip>>,
+         _graphs: &mut ResMut<Assets<AnimationGraph>>| {
             let r_cycle_dur = permutation["r_cycle_dur"] as f32;
             let name = format!("RPlatform_t{:.1}", r_cycle_dur);
             spawn_moving_platform_rotating_instance(
@@ -242,8 +261,6 @@ fn setup_moving_platforms_track(
                 mats,
                 offsets,
                 assets,
-                clips,
-                graphs,
                 &name,
                 R_PLAT_SIZE,
                 r_cycle_dur,
@@ -262,6 +279,44 @@ fn setup_moving_platforms_track(
         &mut animation_graphs,
     );
 
+    // --- Looping Platforms ---
+    let looping_generator =
+        |permutation: &HashMap<String, f64>,
+         cmds: &mut Commands,
+         mshs: &mut ResMut<Assets<Mesh>>,
+         mats: &mut ResMut<Assets<StandardMaterial>>,
+         offsets: &mut ResMut<TrackOffsets>,
+         assets: &Res<TextureAssets>,
+         _clips: &mut ResMut<Assets<AnimationClip>>,
+         _graphs: &mut ResMut<Assets<AnimationGraph>>| {
+            let l_radius = permutation["l_radius"] as f32;
+            let l_dur = permutation["l_dur"] as f32;
+            let name = format!("LPlatform_r{:.1}_t{:.1}", l_radius, l_dur);
+            spawn_moving_platform_looping_instance(
+                cmds,
+                mshs,
+                mats,
+                offsets,
+                assets,
+                &name,
+                L_PLAT_SIZE,
+                l_radius,
+                l_dur,
+                TEX_PLATFORM,
+            );
+        };
+    common::generate_permutations(
+        L_PARAMS,
+        looping_generator,
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut track_offsets,
+        &level_assets,
+        &mut animation_clips,
+        &mut animation_graphs,
+    );
+
     // --- Translate & Rotate Platforms ---
     let tr_generator = |permutation: &HashMap<String, f64>,
                         cmds: &mut Commands,
@@ -269,8 +324,8 @@ fn setup_moving_platforms_track(
                         mats: &mut ResMut<Assets<StandardMaterial>>,
                         offsets: &mut ResMut<TrackOffsets>,
                         assets: &Res<TextureAssets>,
-                        clips: &mut ResMut<Assets<AnimationClip>>,
-                        graphs: &mut ResMut<Assets<AnimationGraph>>| {
+                        _clips: &mut ResMut<Assets<AnimationClip>>,
+                        _graphs: &mut ResMut<Assets<AnimationGraph>>| {
         let tr_dist_x = permutation["tr_dist_x"] as f32;
         let tr_trans_dur = permutation["tr_trans_dur"] as f32;
         let tr_rot_cycle_dur = permutation["tr_rot_cycle_dur"] as f32;
@@ -284,8 +339,6 @@ fn setup_moving_platforms_track(
             mats,
             offsets,
             assets,
-            clips,
-            graphs,
             &name,
             TR_PLAT_SIZE,
             tr_dist_x,
@@ -313,8 +366,8 @@ fn setup_moving_platforms_track(
                         mats: &mut ResMut<Assets<StandardMaterial>>,
                         offsets: &mut ResMut<TrackOffsets>,
                         assets: &Res<TextureAssets>,
-                        clips: &mut ResMut<Assets<AnimationClip>>,
-                        graphs: &mut ResMut<Assets<AnimationGraph>>| {
+                        _clips: &mut ResMut<Assets<AnimationClip>>,
+                        _graphs: &mut ResMut<Assets<AnimationGraph>>| {
         let ct_dist_to_wall = permutation["ct_dist_to_wall"] as f32;
         let ct_move_dur = permutation["ct_move_dur"] as f32;
         let name = format!("CrashTest_d{:.1}_t{:.1}", ct_dist_to_wall, ct_move_dur);
@@ -324,8 +377,6 @@ fn setup_moving_platforms_track(
             mats,
             offsets,
             assets,
-            clips,
-            graphs,
             &name,
             CT_PLAT_SIZE,
             ct_dist_to_wall,
@@ -354,55 +405,15 @@ fn spawn_moving_platform_vertical_instance(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     track_offsets: &mut ResMut<TrackOffsets>,
     level_assets: &Res<TextureAssets>,
-    animation_clips: &mut ResMut<Assets<AnimationClip>>,
-    animation_graphs: &mut ResMut<Assets<AnimationGraph>>,
     name: &str,
     size: Vec3,
     vertical_distance: f32,
-    animation_duration_one_way: f32,
+    duration_one_way: f32,
     texture_index: usize,
 ) {
     let section_center_x = track_offsets.get_and_advance(TRACK_NAME, size.x);
     let platform_start_pos = Vec3::new(section_center_x, BASE_Y + size.y / 2.0, TRACK_Z);
     let platform_end_pos = platform_start_pos + Vec3::Y * vertical_distance;
-    let platform_name_component = Name::new(name.to_string());
-    let target_id = AnimationTargetId::from_name(&platform_name_component);
-
-    let mut clip = AnimationClip::default();
-    if let Ok(interval) = Interval::new(0.0, animation_duration_one_way) {
-        if let Ok(curve) = EasingCurve::new(
-            platform_start_pos,
-            platform_end_pos,
-            EaseFunction::SineInOut,
-        )
-        .reparametrize_linear(interval)
-        {
-            if let Ok(ping_pong_curve) = curve.ping_pong() {
-                clip.add_curve_to_target(
-                    target_id,
-                    AnimatableCurve::new(animated_field!(Transform::translation), ping_pong_curve),
-                );
-            } else {
-                warn!("VPlatform: Failed ping-pong for translation in {}", name);
-            }
-        } else {
-            warn!(
-                "VPlatform: Failed reparametrize for translation in {}",
-                name
-            );
-        }
-    } else {
-        warn!(
-            "VPlatform: Failed interval creation for translation in {}",
-            name
-        );
-    }
-
-    let clip_handle = animation_clips.add(clip);
-    let (graph, node_index) = AnimationGraph::from_clip(clip_handle);
-    let graph_handle = animation_graphs.add(graph);
-    let mut player = AnimationPlayer::default();
-    player.play(node_index).repeat();
 
     let platform_entity = common::spawn_kinematic_cuboid(
         commands,
@@ -413,16 +424,16 @@ fn spawn_moving_platform_vertical_instance(
         size,
         Transform::from_translation(platform_start_pos),
         texture_index,
+        GameLayer::Platform.layers(),
     );
-    commands.entity(platform_entity).insert((
-        platform_name_component,
-        AnimationGraphHandle(graph_handle),
-        player,
-        AnimationTarget {
-            id: target_id,
-            player: platform_entity,
-        },
-    ));
+    commands
+        .entity(platform_entity)
+        .insert(PlatformPath::PingPong {
+            waypoints: vec![platform_start_pos, platform_end_pos],
+            segment_duration: duration_one_way,
+            pause: STOP_PAUSE,
+            ease: EaseFunction::SineInOut,
+        });
 }
 
 fn spawn_moving_platform_horizontal_instance(
@@ -431,12 +442,10 @@ fn spawn_moving_platform_horizontal_instance(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     track_offsets: &mut ResMut<TrackOffsets>,
     level_assets: &Res<TextureAssets>,
-    animation_clips: &mut ResMut<Assets<AnimationClip>>,
-    animation_graphs: &mut ResMut<Assets<AnimationGraph>>,
     name: &str,
     size: Vec3,
     horizontal_distance: f32,
-    animation_duration_one_way: f32,
+    duration_one_way: f32,
     texture_index: usize,
 ) {
     let footprint_x = size.x + horizontal_distance;
@@ -447,44 +456,6 @@ fn spawn_moving_platform_horizontal_instance(
         TRACK_Z,
     );
     let platform_end_pos = platform_start_pos + Vec3::X * horizontal_distance;
-    let platform_name_component = Name::new(name.to_string());
-    let target_id = AnimationTargetId::from_name(&platform_name_component);
-
-    let mut clip = AnimationClip::default();
-    if let Ok(interval) = Interval::new(0.0, animation_duration_one_way) {
-        if let Ok(curve) = EasingCurve::new(
-            platform_start_pos,
-            platform_end_pos,
-            EaseFunction::SineInOut,
-        )
-        .reparametrize_linear(interval)
-        {
-            if let Ok(ping_pong_curve) = curve.ping_pong() {
-                clip.add_curve_to_target(
-                    target_id,
-                    AnimatableCurve::new(animated_field!(Transform::translation), ping_pong_curve),
-                );
-            } else {
-                warn!("HPlatform: Failed ping-pong for translation in {}", name);
-            }
-        } else {
-            warn!(
-                "HPlatform: Failed reparametrize for translation in {}",
-                name
-            );
-        }
-    } else {
-        warn!(
-            "HPlatform: Failed interval creation for translation in {}",
-            name
-        );
-    }
-
-    let clip_handle = animation_clips.add(clip);
-    let (graph, node_index) = AnimationGraph::from_clip(clip_handle);
-    let graph_handle = animation_graphs.add(graph);
-    let mut player = AnimationPlayer::default();
-    player.play(node_index).repeat();
 
     let platform_entity = common::spawn_kinematic_cuboid(
         commands,
@@ -495,16 +466,16 @@ fn spawn_moving_platform_horizontal_instance(
         size,
         Transform::from_translation(platform_start_pos),
         texture_index,
+        GameLayer::Platform.layers(),
     );
-    commands.entity(platform_entity).insert((
-        platform_name_component,
-        AnimationGraphHandle(graph_handle),
-        player,
-        AnimationTarget {
-            id: target_id,
-            player: platform_entity,
-        },
-    ));
+    commands
+        .entity(platform_entity)
+        .insert(PlatformPath::PingPong {
+            waypoints: vec![platform_start_pos, platform_end_pos],
+            segment_duration: duration_one_way,
+            pause: STOP_PAUSE,
+            ease: EaseFunction::SineInOut,
+        });
 }
 
 fn spawn_moving_platform_rotating_instance(
@@ -513,51 +484,59 @@ fn spawn_moving_platform_rotating_instance(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     track_offsets: &mut ResMut<TrackOffsets>,
     level_assets: &Res<TextureAssets>,
-    animation_clips: &mut ResMut<Assets<AnimationClip>>,
-    animation_graphs: &mut ResMut<Assets<AnimationGraph>>,
     name: &str,
     size: Vec3,
-    animation_duration_cycle: f32,
+    duration_cycle: f32,
     texture_index: usize,
 ) {
     let section_center_x = track_offsets.get_and_advance(TRACK_NAME, size.x);
     let platform_pos = Vec3::new(section_center_x, BASE_Y + size.y / 2.0, TRACK_Z);
-    let platform_name_component = Name::new(name.to_string());
-    let target_id = AnimationTargetId::from_name(&platform_name_component);
-
-    let mut clip = AnimationClip::default();
-
-    // --- Rotation Curve using UnevenSampleAutoCurve for full 360 loop ---
-    // Define keyframes for a full 360-degree rotation around Y
-    let times = vec![
-        0.0,                             // Start
-        animation_duration_cycle * 0.25, // 90 degrees
-        animation_duration_cycle * 0.5,  // 180 degrees
-        animation_duration_cycle * 0.75, // 270 degrees
-        animation_duration_cycle,        // 360 degrees (back to start)
-    ];
-    let rotations = vec![
-        Quat::IDENTITY,
-        Quat::from_rotation_y(PI * 0.5),
-        Quat::from_rotation_y(PI),
-        Quat::from_rotation_y(PI * 1.5),
-        Quat::from_rotation_y(PI * 2.0), // Numerically same as Quat::IDENTITY for seamless loop
-    ];
 
-    if let Ok(rotation_curve) = UnevenSampleAutoCurve::new(times.into_iter().zip(rotations)) {
-        clip.add_curve_to_target(
-            target_id,
-            AnimatableCurve::new(animated_field!(Transform::rotation), rotation_curve),
-        );
-    } else {
-        warn!("RPlatform: Failed to build rotation curve for {}", name);
-    }
+    let platform_entity = common::spawn_kinematic_cuboid(
+        commands,
+        meshes,
+        materials,
+        level_assets,
+        name.to_string(),
+        size,
+        Transform::from_translation(platform_pos),
+        texture_index,
+        GameLayer::Platform.layers(),
+    );
+    commands
+        .entity(platform_entity)
+        .insert(PlatformRotation::continuous(Dir3::Y, duration_cycle));
+}
 
-    let clip_handle = animation_clips.add(clip);
-    let (graph, node_index) = AnimationGraph::from_clip(clip_handle);
-    let graph_handle = animation_graphs.add(graph);
-    let mut player = AnimationPlayer::default();
-    player.play(node_index).repeat();
+/// Spawns a platform that loops along a closed spline, rising and falling around a circle.
+fn spawn_moving_platform_looping_instance(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    track_offsets: &mut ResMut<TrackOffsets>,
+    level_assets: &Res<TextureAssets>,
+    name: &str,
+    size: Vec3,
+    radius: f32,
+    duration_loop: f32,
+    texture_index: usize,
+) {
+    let footprint_x = size.x + radius * 2.0;
+    let section_center_x = track_offsets.get_and_advance(TRACK_NAME, footprint_x);
+    let center = Vec3::new(section_center_x, BASE_Y + size.y / 2.0, TRACK_Z);
+
+    // Alternate between low and high points around the circle
+    let points = [
+        center + Vec3::new(-radius, 0.0, 0.0),
+        center + Vec3::new(0.0, L_RISE, -radius),
+        center + Vec3::new(radius, 0.0, 0.0),
+        center + Vec3::new(0.0, L_RISE, radius),
+    ];
+
+    let Some(path) = PlatformPath::looping(points, duration_loop) else {
+        error!("Spawn looping platform '{}': invalid path", name);
+        return;
+    };
 
     let platform_entity = common::spawn_kinematic_cuboid(
         commands,
@@ -566,18 +545,11 @@ fn spawn_moving_platform_rotating_instance(
         level_assets,
         name.to_string(),
         size,
-        Transform::from_translation(platform_pos),
+        Transform::from_translation(points[0]),
         texture_index,
+        GameLayer::Platform.layers(),
     );
-    commands.entity(platform_entity).insert((
-        platform_name_component,
-        AnimationGraphHandle(graph_handle),
-        player,
-        AnimationTarget {
-            id: target_id,
-            player: platform_entity,
-        },
-    ));
+    commands.entity(platform_entity).insert(path);
 }
 
 /// Spawns a platform that translates horizontally and rotates around its Y-axis simultaneously.
@@ -587,8 +559,6 @@ fn spawn_platform_translate_rotate_instance(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     track_offsets: &mut ResMut<TrackOffsets>,
     level_assets: &Res<TextureAssets>,
-    animation_clips: &mut ResMut<Assets<AnimationClip>>,
-    animation_graphs: &mut ResMut<Assets<AnimationGraph>>,
     name: &str,
     size: Vec3,
     translate_dist_x: f32,
@@ -606,75 +576,6 @@ fn spawn_platform_translate_rotate_instance(
     );
     let platform_end_pos = platform_start_pos + Vec3::X * translate_dist_x;
 
-    let platform_name_component = Name::new(name.to_string());
-    let target_id = AnimationTargetId::from_name(&platform_name_component);
-
-    let mut clip = AnimationClip::default();
-
-    // --- Translation Curve (Ping-Pong) ---
-    if let Ok(interval) = Interval::new(0.0, translate_duration_one_way) {
-        if let Ok(trans_curve) = EasingCurve::new(
-            platform_start_pos,
-            platform_end_pos,
-            EaseFunction::SineInOut,
-        )
-        .reparametrize_linear(interval)
-        {
-            if let Ok(ping_pong_trans_curve) = trans_curve.ping_pong() {
-                clip.add_curve_to_target(
-                    target_id,
-                    AnimatableCurve::new(
-                        animated_field!(Transform::translation),
-                        ping_pong_trans_curve,
-                    ),
-                );
-            } else {
-                warn!("TRPlatform: Failed ping-pong for translation in {}", name);
-            }
-        } else {
-            warn!(
-                "TRPlatform: Failed reparametrize for translation in {}",
-                name
-            );
-        }
-    } else {
-        warn!(
-            "TRPlatform: Failed interval creation for translation in {}",
-            name
-        );
-    }
-
-    // --- Rotation Curve (Continuous 360 Loop using UnevenSampleAutoCurve) ---
-    let rot_times = vec![
-        0.0,
-        rotate_cycle_duration * 0.25,
-        rotate_cycle_duration * 0.5,
-        rotate_cycle_duration * 0.75,
-        rotate_cycle_duration,
-    ];
-    let rot_rotations = vec![
-        Quat::IDENTITY,
-        Quat::from_rotation_y(PI * 0.5),
-        Quat::from_rotation_y(PI),
-        Quat::from_rotation_y(PI * 1.5),
-        Quat::from_rotation_y(PI * 2.0),
-    ];
-    if let Ok(rotation_curve) = UnevenSampleAutoCurve::new(rot_times.into_iter().zip(rot_rotations))
-    {
-        clip.add_curve_to_target(
-            target_id,
-            AnimatableCurve::new(animated_field!(Transform::rotation), rotation_curve),
-        );
-    } else {
-        warn!("TRPlatform: Failed to build rotation curve for {}", name);
-    }
-
-    let clip_handle = animation_clips.add(clip);
-    let (graph, node_index) = AnimationGraph::from_clip(clip_handle);
-    let graph_handle = animation_graphs.add(graph);
-    let mut player = AnimationPlayer::default();
-    player.play(node_index).repeat();
-
     let platform_entity = common::spawn_kinematic_cuboid(
         commands,
         meshes,
@@ -684,15 +585,16 @@ fn spawn_platform_translate_rotate_instance(
         size,
         Transform::from_translation(platform_start_pos),
         texture_index,
+        GameLayer::Platform.layers(),
     );
     commands.entity(platform_entity).insert((
-        platform_name_component,
-        AnimationGraphHandle(graph_handle),
-        player,
-        AnimationTarget {
-            id: target_id,
-            player: platform_entity,
+        PlatformPath::PingPong {
+            waypoints: vec![platform_start_pos, platform_end_pos],
+            segment_duration: translate_duration_one_way,
+            pause: 0.0,
+            ease: EaseFunction::SineInOut,
         },
+        PlatformRotation::continuous(Dir3::Y, rotate_cycle_duration),
     ));
 }
 
@@ -703,8 +605,6 @@ fn spawn_platform_crash_test_instance(
     materials: &mut ResMut<Assets<StandardMaterial>>,
     track_offsets: &mut ResMut<TrackOffsets>,
     level_assets: &Res<TextureAssets>,
-    animation_clips: &mut ResMut<Assets<AnimationClip>>,
-    animation_graphs: &mut ResMut<Assets<AnimationGraph>>,
     name: &str,
     platform_size: Vec3,
     distance_to_wall: f32,
@@ -726,6 +626,7 @@ fn spawn_platform_crash_test_instance(
         CT_WALL_SIZE,
         Transform::from_translation(wall_pos),
         TEX_OBSTACLE_WALL,
+        GameLayer::World.layers(),
     );
 
     let platform_start_x = section_center_x - total_section_width / 2.0 + platform_size.x / 2.0;
@@ -738,36 +639,6 @@ fn spawn_platform_crash_test_instance(
         platform_start_pos.z,
     );
 
-    let platform_name_component = Name::new(name.to_string());
-    let target_id = AnimationTargetId::from_name(&platform_name_component);
-
-    let mut clip = AnimationClip::default();
-    if let Ok(interval) = Interval::new(0.0, move_duration) {
-        if let Ok(curve) =
-            EasingCurve::new(platform_start_pos, platform_end_pos, EaseFunction::Linear)
-                .reparametrize_linear(interval)
-        {
-            if let Ok(ping_pong_curve) = curve.ping_pong() {
-                clip.add_curve_to_target(
-                    target_id,
-                    AnimatableCurve::new(animated_field!(Transform::translation), ping_pong_curve),
-                );
-            } else {
-                warn!("CrashTest: Failed ping-pong for {}", name);
-            }
-        } else {
-            warn!("CrashTest: Failed reparametrize for {}", name);
-        }
-    } else {
-        warn!("CrashTest: Failed interval creation for {}", name);
-    }
-
-    let clip_handle = animation_clips.add(clip);
-    let (graph, node_index) = AnimationGraph::from_clip(clip_handle);
-    let graph_handle = animation_graphs.add(graph);
-    let mut player = AnimationPlayer::default();
-    player.play(node_index).repeat();
-
     let platform_entity = common::spawn_kinematic_cuboid(
         commands,
         meshes,
@@ -777,14 +648,14 @@ fn spawn_platform_crash_test_instance(
         platform_size,
         Transform::from_translation(platform_start_pos),
         texture_index,
+        GameLayer::Platform.layers(),
     );
-    commands.entity(platform_entity).insert((
-        platform_name_component,
-        AnimationGraphHandle(graph_handle),
-        player,
-        AnimationTarget {
-            id: target_id,
-            player: platform_entity,
-        },
-    ));
+    commands
+        .entity(platform_entity)
+        .insert(PlatformPath::PingPong {
+            waypoints: vec![platform_start_pos, platform_end_pos],
+            segment_duration: move_duration,
+            pause: 0.0,
+            ease: EaseFunction::Linear,
+        });
 }
@@ -1,6 +1,9 @@
-use crate::level::{
-    common::{self, Param},
-    utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+use crate::{
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+    },
 };
 use avian3d::prelude::Collider;
 use bevy::prelude::*;
@@ -129,5 +132,6 @@ fn spawn_beam_instance(
         beam_size,
         Transform::from_translation(beam_pos),
         texture_index,
+        GameLayer::World.layers(),
     );
 }
@@ -0,0 +1,131 @@
+use crate::{
+    character::OneWayPlatform,
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, TextureAssets, TrackOffsets},
+    },
+};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+// --- Plugin Definition ---
+pub struct OneWayPlatformsTrackPlugin;
+
+impl Plugin for OneWayPlatformsTrackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Startup,
+            setup_one_way_platforms_track.after(super::super::load_assets_and_setup),
+        );
+    }
+}
+
+// --- Constants ---
+const TRACK_NAME: &str = "OneWayPlatforms";
+const TRACK_Z: f32 = 160.0;
+const TEX_PLATFORM: usize = 2 * 13 + 4;
+const PLATFORM_DEPTH: f32 = 4.0;
+
+// --- Parameter Ranges ---
+// 3 * 2 = 6 instances
+const PARAMS: &[(&str, Param)] = &[
+    // Height of the top face above the ground
+    (
+        "height",
+        Param::Float {
+            start: 1.0,
+            end: 3.0,
+            step: 1.0,
+        },
+    ), // Heights: 1.0, 2.0, 3.0
+    (
+        "thickness",
+        Param::Float {
+            start: 0.1,
+            end: 0.5,
+            step: 0.4,
+        },
+    ), // Thicknesses: 0.1, 0.5
+];
+const PLATFORM_WIDTH: f32 = 3.0;
+
+// --- Setup System ---
+fn setup_one_way_platforms_track(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut track_offsets: ResMut<TrackOffsets>,
+    level_assets: Res<TextureAssets>,
+    mut animation_clips: ResMut<Assets<AnimationClip>>, // Needed for signature
+    mut animation_graphs: ResMut<Assets<AnimationGraph>>,
+) {
+    info!("Generating track: {}", TRACK_NAME);
+
+    let generator_closure =
+        |permutation: &HashMap<String, f64>,
+         cmds: &mut Commands,
+         mshs: &mut ResMut<Assets<Mesh>>,
+         mats: &mut ResMut<Assets<StandardMaterial>>,
+         offsets: &mut ResMut<TrackOffsets>,
+         assets: &Res<TextureAssets>,
+         _clips: &mut ResMut<Assets<AnimationClip>>,
+         _graphs: &mut ResMut<Assets<AnimationGraph>>| {
+            let height = permutation["height"] as f32;
+            let thickness = permutation["thickness"] as f32;
+
+            let name = format!("OneWayPlatform_h{:.1}_t{:.1}", height, thickness);
+
+            spawn_one_way_platform_instance(
+                cmds, mshs, mats, offsets, assets, &name, height, thickness,
+            );
+        };
+
+    common::generate_permutations(
+        PARAMS,
+        generator_closure,
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut track_offsets,
+        &level_assets,
+        &mut animation_clips,
+        &mut animation_graphs,
+    );
+}
+
+/// Spawns a single one-way platform floating above the ground, with its top face at `height`.
+fn spawn_one_way_platform_instance(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    track_offsets: &mut ResMut<TrackOffsets>,
+    level_assets: &Res<TextureAssets>,
+    name: &str,
+    height: f32,
+    thickness: f32,
+) {
+    let section_center_x = track_offsets.get_and_advance(TRACK_NAME, PLATFORM_WIDTH);
+
+    if thickness <= 0.0 || height <= thickness {
+        warn!("Skipping one-way platform '{}': invalid size.", name);
+        return;
+    }
+
+    let platform_entity = common::spawn_static_cuboid(
+        commands,
+        meshes,
+        materials,
+        level_assets,
+        name.to_string(),
+        Vec3::new(PLATFORM_WIDTH, thickness, PLATFORM_DEPTH),
+        Transform::from_xyz(
+            section_center_x,
+            BASE_Y + height - thickness / 2.0,
+            TRACK_Z + PLATFORM_DEPTH / 2.0,
+        ),
+        TEX_PLATFORM,
+        GameLayer::World.layers(),
+    );
+    commands.entity(platform_entity).insert(OneWayPlatform);
+}
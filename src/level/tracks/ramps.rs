@@ -1,6 +1,10 @@
-use crate::level::{
-    common::{self, Param},
-    utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+use crate::{
+    character::SurfaceMaterial,
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+    },
 };
 use bevy::prelude::*;
 use std::collections::HashMap;
@@ -21,6 +25,7 @@ impl Plugin for RampsTrackPlugin {
 const TRACK_NAME: &str = "Ramps";
 const TRACK_Z: f32 = 0.0;
 const TEX_RAMP: usize = 4 * 13 + 7;
+const TEX_ICY_RAMP: usize = 13 + 7;
 const WIDTH: f32 = 4.0; // Keep width constant for ramps in this example
 const THICKNESS: f32 = 0.2;
 
@@ -42,6 +47,15 @@ const PARAMS: &[(&str, Param)] = &[
             step: 15.0,
         },
     ), // Angle in degrees
+    // 0: regular ramp, 1: icy ramp
+    (
+        "icy",
+        Param::Int {
+            start: 0,
+            end: 1,
+            step: 1,
+        },
+    ),
 ];
 
 // --- Setup System ---
@@ -67,8 +81,20 @@ fn setup_ramps_track(
          _graphs: &mut ResMut<Assets<AnimationGraph>>| {
             let length = permutation["length"] as f32;
             let angle_degrees = permutation["angle"] as f32;
-
-            let name = format!("Ramp_l{:.1}_a{:.0}", length, angle_degrees);
+            let icy = permutation["icy"] as i32 == 1;
+
+            let (name, material, texture_index) = match icy {
+                true => (
+                    format!("IcyRamp_l{:.1}_a{:.0}", length, angle_degrees),
+                    Some(SurfaceMaterial::ICE),
+                    TEX_ICY_RAMP,
+                ),
+                false => (
+                    format!("Ramp_l{:.1}_a{:.0}", length, angle_degrees),
+                    None,
+                    TEX_RAMP,
+                ),
+            };
 
             spawn_ramp_instance(
                 cmds,
@@ -81,7 +107,8 @@ fn setup_ramps_track(
                 length,
                 THICKNESS,
                 angle_degrees,
-                TEX_RAMP,
+                texture_index,
+                material,
             );
         };
 
@@ -112,6 +139,7 @@ fn spawn_ramp_instance(
     thickness: f32,
     angle_degrees: f32,
     texture_index: usize,
+    material: Option<SurfaceMaterial>,
 ) {
     let section_center_x = track_offsets.get_and_advance(TRACK_NAME, width);
 
@@ -137,7 +165,7 @@ fn spawn_ramp_instance(
     let transform = Transform::from_xyz(section_center_x, ramp_center_y, ramp_center_z)
         .with_rotation(Quat::from_rotation_x(-angle_rad));
 
-    common::spawn_static_cuboid(
+    let ramp_entity = common::spawn_static_cuboid(
         commands,
         meshes,
         materials,
@@ -146,5 +174,10 @@ fn spawn_ramp_instance(
         ramp_size,
         transform,
         texture_index,
+        GameLayer::World.layers(),
     );
+
+    if let Some(material) = material {
+        commands.entity(ramp_entity).insert(material);
+    }
 }
@@ -1,6 +1,9 @@
-use crate::level::{
-    common::{self, Param},
-    utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+use crate::{
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+    },
 };
 use bevy::prelude::*;
 use std::collections::HashMap;
@@ -149,6 +152,7 @@ fn spawn_ridge_instance(
         plane_size,
         transform_left,
         texture_index,
+        GameLayer::World.layers(),
     );
 
     // Spawn Right Plane
@@ -168,5 +172,6 @@ fn spawn_ridge_instance(
         plane_size,
         transform_right,
         texture_index,
+        GameLayer::World.layers(),
     );
 }
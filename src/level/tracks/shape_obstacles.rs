@@ -1,6 +1,9 @@
-use crate::level::{
-    common,
-    utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+use crate::{
+    layers::GameLayer,
+    level::{
+        common,
+        utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+    },
 };
 use avian3d::prelude::Collider; // Use Collider enum directly
 use bevy::prelude::*;
@@ -115,6 +118,7 @@ fn setup_shape_obstacles_track(
             transform,
             TEX_OBSTACLE,
             *bbox, // Pass bounding box
+            GameLayer::World.layers(),
         );
     }
 }
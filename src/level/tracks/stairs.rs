@@ -1,9 +1,13 @@
 // src/level/plugins/stairs.rs
 
-use crate::level::{
-    common::{self, Param}, // Use common helpers and Param enum
-    utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets}, // Use resources/constants
+use crate::{
+    layers::GameLayer,
+    level::{
+        common::{self, Param}, // Use common helpers and Param enum
+        utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets}, // Use resources/constants
+    },
 };
+use avian3d::prelude::CollisionLayers;
 use bevy::prelude::*;
 use std::collections::HashMap;
 
@@ -25,6 +29,7 @@ impl Plugin for StairsTrackPlugin {
 const TRACK_NAME: &str = "Stairs";
 const TRACK_Z: f32 = -20.0;
 const TEX_STAIR: usize = 9;
+const TEX_NO_STEP_STAIR: usize = 2 * 13 + 9;
 
 // --- Parameter Ranges ---
 const PARAMS: &[(&str, Param)] = &[
@@ -110,6 +115,7 @@ fn setup_stairs_track(
                 step_height,
                 step_depth,
                 TEX_STAIR,
+                GameLayer::World.layers(),
             );
         };
 
@@ -125,6 +131,22 @@ fn setup_stairs_track(
         &mut animation_clips, // Pass animation resources
         &mut animation_graphs,
     );
+
+    // Stairs on the no-step layer, characters have to jump up each step
+    spawn_steps_instance(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut track_offsets,
+        &level_assets,
+        "Stairs_NoStep_w4.0_h0.2_d0.4_n4",
+        4,
+        4.0,
+        0.2,
+        0.4,
+        TEX_NO_STEP_STAIR,
+        GameLayer::NoStep.layers(),
+    );
 }
 
 /// Spawns a single instance (set) of steps on the stairs track.
@@ -141,6 +163,7 @@ fn spawn_steps_instance(
     step_height: f32,
     step_depth: f32,
     texture_index: usize,
+    layers: CollisionLayers,
 ) {
     let section_center_x = track_offsets.get_and_advance(TRACK_NAME, width);
     let step_start_y = BASE_Y;
@@ -175,6 +198,7 @@ fn spawn_steps_instance(
             step_size,
             Transform::from_xyz(0.0, relative_y, relative_z), // Relative to parent
             texture_index,
+            layers,
         );
         commands.entity(parent_entity).add_child(step_entity);
     }
@@ -1,6 +1,10 @@
-use crate::level::{
-    common::{self, Param},
-    utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+use crate::{
+    character::SurfaceMaterial,
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, Geometry, TextureAssets, TrackOffsets},
+    },
 };
 use bevy::prelude::*;
 use core::f32;
@@ -22,10 +26,11 @@ impl Plugin for UnevenPatchesTrackPlugin {
 const TRACK_NAME: &str = "UnevenPatches";
 const TRACK_Z: f32 = 40.0; // Place this track forward
 const TEX_PATCH: usize = 0; // Example texture (ground?)
+const TEX_MUD_PATCH: usize = 4 * 13 + 1;
 const PATCH_THICKNESS: f32 = 0.1;
 
 // --- Parameter Ranges ---
-// 2 * 2 * 2 = 8 instances
+// 1 * 1 * 2 * 2 = 4 instances
 const PARAMS: &[(&str, Param)] = &[
     // Size of the square grid (e.g., 3 means 3x3 patches)
     (
@@ -54,6 +59,15 @@ const PARAMS: &[(&str, Param)] = &[
             step: 0.1,
         },
     ), // Variations: 0.05, 0.15
+    // 0: regular patches, 1: sticky mud patches
+    (
+        "mud",
+        Param::Int {
+            start: 0,
+            end: 1,
+            step: 1,
+        },
+    ),
 ];
 // Spacing will be calculated based on patch_size (e.g., slight overlap)
 const SPACING_FACTOR: f32 = 0.9; // Multiplier for patch_size to get spacing
@@ -82,10 +96,16 @@ fn setup_uneven_patches_track(
             let grid_dim = permutation["grid_dim"] as i32;
             let patch_size = permutation["patch_size"] as f32;
             let max_h_var = permutation["max_h_var"] as f32;
+            let mud = permutation["mud"] as i32 == 1;
+
+            let (prefix, material, texture_index) = match mud {
+                true => ("MudPatches", Some(SurfaceMaterial::MUD), TEX_MUD_PATCH),
+                false => ("Patches", None, TEX_PATCH),
+            };
 
             let name = format!(
-                "Patches_{}x{}_s{:.1}_h{:.2}",
-                grid_dim, grid_dim, patch_size, max_h_var
+                "{}_{}x{}_s{:.1}_h{:.2}",
+                prefix, grid_dim, grid_dim, patch_size, max_h_var
             );
             let patch_spacing = patch_size * SPACING_FACTOR;
 
@@ -100,7 +120,8 @@ fn setup_uneven_patches_track(
                 patch_size,
                 patch_spacing,
                 max_h_var,
-                TEX_PATCH,
+                texture_index,
+                material,
             );
         };
 
@@ -130,6 +151,7 @@ fn spawn_patch_grid_instance(
     patch_spacing: f32, // Spacing between patch centers
     max_h_var: f32,     // Max height variation (+/- from BASE_Y)
     texture_index: usize,
+    material: Option<SurfaceMaterial>,
 ) {
     let grid_total_width = grid_dim as f32 * patch_spacing;
     // Footprint along X axis is the total width of the grid
@@ -173,7 +195,11 @@ fn spawn_patch_grid_instance(
                 patch_size_vec,
                 Transform::from_xyz(patch_center_x, patch_y, patch_center_z),
                 texture_index,
+                GameLayer::World.layers(),
             );
+            if let Some(material) = material {
+                commands.entity(patch_entity).insert(material);
+            }
             commands.entity(parent_entity).add_child(patch_entity); // Optional parenting
         }
     }
@@ -0,0 +1,200 @@
+use crate::{
+    layers::GameLayer,
+    level::{
+        common::{self, Param},
+        utils::{BASE_Y, TextureAssets, TrackOffsets},
+    },
+    swimming::Water,
+};
+use avian3d::prelude::{Collider, RigidBody};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+// --- Plugin Definition ---
+pub struct WaterTrackPlugin;
+
+impl Plugin for WaterTrackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Startup,
+            setup_water_track.after(super::super::load_assets_and_setup),
+        );
+    }
+}
+
+// --- Constants ---
+const TRACK_NAME: &str = "Water";
+const TRACK_Z: f32 = 120.0; // Place this track far forward
+const TEX_POOL_WALL: usize = 13 + 4; // Example texture
+const TEX_POOL_RAMP: usize = 13 + 7;
+const POOL_SIZE: f32 = 6.0; // Inner width and length of each pool
+const WALL_THICKNESS: f32 = 0.3;
+const WATER_FREEBOARD: f32 = 0.1; // Distance from the water surface to the top of the pool walls
+const RAMP_ANGLE: f32 = 20.0; // Angle in degrees of the ramp leading up to the pool rim
+const RAMP_THICKNESS: f32 = 0.2;
+
+// --- Parameter Ranges ---
+// 6 instances
+const PARAMS: &[(&str, Param)] = &[
+    // Depth of the pool, the shallower pools are shallower than the character is tall
+    (
+        "depth",
+        Param::Float {
+            start: 0.5,
+            end: 3.0,
+            step: 0.5,
+        },
+    ), // Depths: 0.5, 1.0, 1.5, 2.0, 2.5, 3.0
+];
+
+// --- Setup System ---
+fn setup_water_track(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut track_offsets: ResMut<TrackOffsets>,
+    level_assets: Res<TextureAssets>,
+    mut animation_clips: ResMut<Assets<AnimationClip>>, // Needed for signature
+    mut animation_graphs: ResMut<Assets<AnimationGraph>>,
+) {
+    info!("Generating track: {}", TRACK_NAME);
+
+    let generator_closure =
+        |permutation: &HashMap<String, f64>,
+         cmds: &mut Commands,
+         mshs: &mut ResMut<Assets<Mesh>>,
+         mats: &mut ResMut<Assets<StandardMaterial>>,
+         offsets: &mut ResMut<TrackOffsets>,
+         assets: &Res<TextureAssets>,
+         _clips: &mut ResMut<Assets<AnimationClip>>,
+         _graphs: &mut ResMut<Assets<AnimationGraph>>| {
+            let depth = permutation["depth"] as f32;
+
+            let name = format!("Pool_d{:.1}", depth);
+
+            spawn_pool_instance(cmds, mshs, mats, offsets, assets, &name, depth);
+        };
+
+    common::generate_permutations(
+        PARAMS,
+        generator_closure,
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut track_offsets,
+        &level_assets,
+        &mut animation_clips,
+        &mut animation_graphs,
+    );
+}
+
+/// Spawns a single pool: four walls, a water volume and a ramp leading up to the rim.
+fn spawn_pool_instance(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    track_offsets: &mut ResMut<TrackOffsets>,
+    level_assets: &Res<TextureAssets>,
+    name: &str,
+    depth: f32,
+) {
+    let outer_size = POOL_SIZE + WALL_THICKNESS * 2.0;
+    let section_center_x = track_offsets.get_and_advance(TRACK_NAME, outer_size);
+
+    if depth <= WATER_FREEBOARD {
+        warn!("Skipping pool '{}': too shallow.", name);
+        return;
+    }
+
+    let parent_entity = commands
+        .spawn((
+            Transform::from_xyz(section_center_x, BASE_Y, TRACK_Z),
+            Name::new(name.to_string()),
+        ))
+        .id();
+
+    // --- Walls ---
+    let wall_offset = POOL_SIZE / 2.0 + WALL_THICKNESS / 2.0;
+    let walls = [
+        (
+            "Front",
+            Vec3::new(outer_size, depth, WALL_THICKNESS),
+            Vec3::new(0.0, depth / 2.0, wall_offset),
+        ),
+        (
+            "Back",
+            Vec3::new(outer_size, depth, WALL_THICKNESS),
+            Vec3::new(0.0, depth / 2.0, -wall_offset),
+        ),
+        (
+            "Left",
+            Vec3::new(WALL_THICKNESS, depth, POOL_SIZE),
+            Vec3::new(-wall_offset, depth / 2.0, 0.0),
+        ),
+        (
+            "Right",
+            Vec3::new(WALL_THICKNESS, depth, POOL_SIZE),
+            Vec3::new(wall_offset, depth / 2.0, 0.0),
+        ),
+    ];
+
+    for (side, size, position) in walls {
+        let wall_entity = common::spawn_static_cuboid(
+            commands,
+            meshes,
+            materials,
+            level_assets,
+            format!("{}_Wall{}", name, side),
+            size,
+            Transform::from_translation(position), // Relative to parent
+            TEX_POOL_WALL,
+            GameLayer::World.layers(),
+        );
+        commands.entity(parent_entity).add_child(wall_entity);
+    }
+
+    // --- Water ---
+    let water_height = depth - WATER_FREEBOARD;
+    let water_size = Vec3::new(POOL_SIZE, water_height, POOL_SIZE);
+    let water_entity = commands
+        .spawn((
+            Mesh3d(meshes.add(Cuboid::from_size(water_size))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgba(0.1, 0.4, 0.8, 0.4),
+                alpha_mode: AlphaMode::Blend,
+                perceptual_roughness: 0.1,
+                ..default()
+            })),
+            Transform::from_xyz(0.0, water_height / 2.0, 0.0), // Relative to parent
+            RigidBody::Static,
+            Collider::cuboid(water_size.x, water_size.y, water_size.z),
+            GameLayer::Trigger.layers(),
+            Water,
+            Name::new(format!("{}_Water", name)),
+        ))
+        .id();
+    commands.entity(parent_entity).add_child(water_entity);
+
+    // --- Ramp up to the rim, rising towards the back wall ---
+    let angle_rad = RAMP_ANGLE.to_radians();
+    let ramp_length = (depth - RAMP_THICKNESS * angle_rad.cos()).max(0.1) / angle_rad.sin();
+    let ramp_size = Vec3::new(POOL_SIZE / 2.0, RAMP_THICKNESS, ramp_length);
+
+    let ramp_center_y =
+        (ramp_length / 2.0) * angle_rad.sin() + (RAMP_THICKNESS / 2.0) * angle_rad.cos();
+    let ramp_center_z = -POOL_SIZE / 2.0 - WALL_THICKNESS - (ramp_length / 2.0) * angle_rad.cos();
+
+    let ramp_entity = common::spawn_static_cuboid(
+        commands,
+        meshes,
+        materials,
+        level_assets,
+        format!("{}_Ramp", name),
+        ramp_size,
+        Transform::from_xyz(0.0, ramp_center_y, ramp_center_z) // Relative to parent
+            .with_rotation(Quat::from_rotation_x(-angle_rad)),
+        TEX_POOL_RAMP,
+        GameLayer::World.layers(),
+    );
+    commands.entity(parent_entity).add_child(ramp_entity);
+}
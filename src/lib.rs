@@ -2,10 +2,22 @@ use bevy::prelude::*;
 
 pub mod camera;
 pub mod character;
+pub mod climbing;
+pub mod crouch_slide;
+pub mod dash;
+pub mod forces;
 pub mod input;
+pub mod layers;
+pub mod ledge_grab;
 pub mod level;
 pub mod move_and_slide;
 pub mod movement;
+pub mod movement_mode;
+pub mod noclip;
+pub mod sliding;
+pub mod sprint;
+pub mod swimming;
+pub mod wall_movement;
 
 #[derive(Component)]
 #[relationship(relationship_target = Attachments)]
@@ -10,10 +10,19 @@ use kcc_prototype::{
     camera::FollowOffset,
     camera::{CameraPlugin, MainCamera},
     character::*,
+    climbing::ClimbingPlugin,
+    crouch_slide::CrouchSlidePlugin,
+    dash::DashPlugin,
     input::{DefaultContext, InputPlugin},
     input::{FlyCameraContext, OrbitCameraContext},
+    ledge_grab::LedgeGrabPlugin,
     level::LevelGeneratorPlugin,
     movement::{Character, KCCPlugin},
+    noclip::NoclipPlugin,
+    sliding::SlidingPlugin,
+    sprint::SprintPlugin,
+    swimming::SwimmingPlugin,
+    wall_movement::WallMovementPlugin,
 };
 
 fn main() -> AppExit {
@@ -26,6 +35,18 @@ fn main() -> AppExit {
             PhysicsDebugPlugin::default(),
             LevelGeneratorPlugin,
             KCCPlugin,
+            // Character abilities
+            (
+                LedgeGrabPlugin,
+                WallMovementPlugin,
+                SwimmingPlugin,
+                ClimbingPlugin,
+                NoclipPlugin,
+                SprintPlugin,
+                DashPlugin,
+                SlidingPlugin,
+                CrouchSlidePlugin,
+            ),
             PhysicsDiagnosticsPlugin,
             PhysicsDiagnosticsUiPlugin,
         ))
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use avian3d::prelude::*;
 use bevy::prelude::*;
 
@@ -75,6 +77,8 @@ pub struct MoveAndSlideHit<'a> {
     pub remaining_motion: f32,
     /// This is the remaining time of the movement for all substeps. You can override this value to stop the movement early.
     pub remaining_time: &'a mut f32,
+    /// Set this to move through the hit entity for the rest of the movement instead of colliding with it.
+    pub pass_through: &'a mut bool,
 }
 
 // @todo: lets make this take in a struct instead of a bunch of arguments,
@@ -106,6 +110,9 @@ pub fn move_and_slide(
 
     let mut hits = Vec::with_capacity(config.max_substeps as usize);
 
+    // Only cloned when the `on_hit` callback passes through an entity
+    let mut filter = Cow::Borrowed(filter);
+
     for substep in 0..config.max_substeps {
         let Ok((direction, max_distance)) = Dir3::new_and_length(velocity * remaining_time) else {
             break;
@@ -119,7 +126,7 @@ pub fn move_and_slide(
             max_distance,
             rotation,
             spatial_query,
-            filter,
+            &filter,
         ) else {
             // No collision, move the full remaining distance
             translation += direction * max_distance;
@@ -132,8 +139,10 @@ pub fn move_and_slide(
         // Move the transform to just before the point of collision
         translation += direction * safe_movement;
 
+        let mut pass_through = false;
+
         // Trigger callbacks
-        if !on_hit(&mut MoveAndSlideHit {
+        let slide = on_hit(&mut MoveAndSlideHit {
             substep,
             hit_data: hit,
             translation: &mut translation,
@@ -142,7 +151,16 @@ pub fn move_and_slide(
             motion: safe_movement,
             remaining_motion: max_distance - safe_movement,
             remaining_time: &mut remaining_time,
-        }) {
+            pass_through: &mut pass_through,
+        });
+
+        if pass_through {
+            // Ignore the entity from now on and keep moving
+            filter.to_mut().excluded_entities.insert(hit.entity);
+            continue;
+        }
+
+        if !slide {
             // User decided to not slide, continue to next substep
             continue;
         }
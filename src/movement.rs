@@ -1,8 +1,9 @@
-use std::f32::consts::PI;
+use std::{borrow::Cow, f32::consts::PI};
 
 use avian3d::{
     prelude::{
-        Collider, CollisionLayers, PhysicsSet, RigidBody, Sensor, SpatialQuery, SpatialQueryFilter,
+        AngularVelocity, Collider, CollisionLayers, LinearVelocity, RigidBody, Sensor,
+        SpatialQuery, SpatialQueryFilter,
     },
     sync::PreviousGlobalTransform,
 };
@@ -10,10 +11,13 @@ use bevy::prelude::*;
 use bevy_enhanced_input::prelude::{ActionState, Actions};
 
 use crate::{
-    camera::MainCamera,
+    camera::{MainCamera, PlatformYaw},
     character::*,
-    input::{self, DefaultContext, Jump},
+    forces::ExternalForces,
+    input::{self, Crouch, DefaultContext, Jump},
+    layers::GameLayer,
     move_and_slide::*,
+    movement_mode::{self, MovementMode, MovementSet},
 };
 
 // @todo: we should probably move all of this into an example file, then make the project a lib instead of a bin.
@@ -22,36 +26,104 @@ pub struct KCCPlugin;
 
 impl Plugin for KCCPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(FixedPreUpdate, update_character_filter);
+        app.add_plugins(movement_mode::plugin);
+        app.add_systems(
+            FixedPreUpdate,
+            (update_passing_through, update_character_filter).chain(),
+        );
         app.add_systems(
             FixedUpdate,
-            (movement, platform_movement.after(PhysicsSet::Sync)),
+            (
+                movement.in_set(MovementSet::Update),
+                // Platforms are moved by physics after this, so move characters along last
+                platform_movement.after(MovementSet::Update),
+            ),
         );
         app.add_systems(Update, jump_input);
+        app.add_observer(move_sensors_to_trigger_layer);
     }
 }
 
 /// Cache the [`SpatialQueryFilter`] of the character to avoid re-allocating the excluded entities map every time it's used.
 ///
+/// Only rebuilt when the character's [`CollisionLayers`], [`IgnoredEntities`] or [`PassingThrough`] change.
+///
 /// This has to be a seperate component because otherwise the `character` cannot be mutated during a `move_and_slide` loop.
 #[derive(Component, Reflect, Default, Debug)]
 #[reflect(Component)]
-struct CharacterFilter(SpatialQueryFilter);
+pub(crate) struct CharacterFilter(pub(crate) SpatialQueryFilter);
 
 fn update_character_filter(
-    mut query: Query<(Entity, &mut CharacterFilter, &CollisionLayers)>,
-    sensors: Query<Entity, With<Sensor>>,
+    mut query: Query<
+        (
+            Entity,
+            &mut CharacterFilter,
+            &CollisionLayers,
+            &IgnoredEntities,
+            &PassingThrough,
+        ),
+        Or<(
+            Changed<CollisionLayers>,
+            Changed<IgnoredEntities>,
+            Changed<PassingThrough>,
+        )>,
+    >,
 ) {
-    for (entity, mut filter, collidion_layers) in &mut query {
-        // Filter out any entities that's not in the character's collision filter
-        filter.0.mask = collidion_layers.filters.into();
+    for (entity, mut filter, collision_layers, ignored, passing_through) in &mut query {
+        // Filter out any entities that's not in the character's collision filter, this includes sensors on the trigger layer
+        filter.0.mask = collision_layers.filters;
 
-        // Filter out all sensor entities along with the character entity
+        // Filter out the character entity along with the entities it explicitly ignores
         filter.0.excluded_entities.clear();
         filter
             .0
             .excluded_entities
-            .extend(sensors.iter().chain([entity]));
+            .extend(ignored.0.iter().copied().chain([entity]));
+
+        // Filter out the one-way platforms the character is passing through
+        filter
+            .0
+            .excluded_entities
+            .extend(passing_through.0.iter().copied());
+    }
+}
+
+/// Move sensors without collision layers of their own to [`GameLayer::Trigger`], so character movement ignores them.
+///
+/// Colliders come with the default layers, so sensors on those are moved as well.
+fn move_sensors_to_trigger_layer(
+    trigger: Trigger<OnAdd, Sensor>,
+    mut commands: Commands,
+    query: Query<&CollisionLayers>,
+) {
+    let layers = query.get(trigger.target()).ok();
+    if layers.is_none_or(|layers| *layers == CollisionLayers::default()) {
+        commands
+            .entity(trigger.target())
+            .insert(GameLayer::Trigger.layers());
+    }
+}
+
+/// Stop passing through one-way platforms once the character no longer overlaps them.
+fn update_passing_through(
+    mut query: Query<(Entity, &Transform, &Collider, &mut PassingThrough)>,
+    spatial_query: SpatialQuery,
+) {
+    for (entity, transform, collider, mut passing_through) in &mut query {
+        if passing_through.0.is_empty() {
+            continue;
+        }
+
+        let overlapping = spatial_query.shape_intersections(
+            collider,
+            transform.translation,
+            transform.rotation,
+            &SpatialQueryFilter::from_excluded_entities([entity]),
+        );
+
+        passing_through
+            .0
+            .retain(|platform| overlapping.contains(platform));
     }
 }
 
@@ -59,23 +131,51 @@ fn update_character_filter(
 #[require(
     RigidBody = RigidBody::Kinematic,
     Collider = Capsule3d::new(EXAMPLE_CHARACTER_RADIUS, EXAMPLE_CHARACTER_CAPSULE_LENGTH),
+    CollisionLayers = GameLayer::Character.layers(),
     CharacterFilter,
+    MovementMode,
+    ExternalForces,
+    PlatformYaw,
+    GroundContacts,
+    IgnoredEntities,
+    PassingThrough,
 )]
 pub struct Character {
-    velocity: Vec3,
-    ground: Option<Ground>,
-    previous_ground: Option<Ground>,
-    up: Dir3,
-    config: MoveAndSlideConfig,
+    pub(crate) velocity: Vec3,
+    pub(crate) ground: Option<Ground>,
+    pub(crate) previous_ground: Option<Ground>,
+    pub(crate) up: Dir3,
+    /// The target speed of regular movement.
+    pub(crate) speed: f32,
+    /// Velocity of the ground at the character's position, updated every tick while grounded.
+    pub(crate) ground_velocity: Vec3,
+    /// Which axes of the ground velocity are kept when jumping or walking off a moving platform.
+    pub platform_inheritance: PlatformInheritance,
+    /// Snapping down to the ground when walking down steps or over crests.
+    pub ground_snapping: GroundSnapping,
+    /// Speed changes when walking up or down slopes.
+    pub slope_speed: SlopeSpeed,
+    /// Standing on edges and not walking off of ledges.
+    pub edges: EdgeHandling,
+    pub(crate) config: MoveAndSlideConfig,
 }
 
 impl Character {
     /// Launch the character, clearing the grounded state if launched away from the `ground` normal.
+    ///
+    /// The velocity of the ground at the moment of launch is kept according to the [`PlatformInheritance`].
     pub fn launch(&mut self, impulse: Vec3) {
         if let Some(ground) = self.ground {
             // Clear grounded if launched away from the ground
             if ground.normal.dot(impulse) > 0.0 {
                 self.ground = None;
+
+                // Inherit the platform velocity now rather than when the ground is lost on the next tick
+                self.velocity += self
+                    .platform_inheritance
+                    .apply(self.ground_velocity, self.up);
+                self.previous_ground = None;
+                self.ground_velocity = Vec3::ZERO;
             }
         }
 
@@ -102,51 +202,168 @@ impl Default for Character {
             ground: None,
             previous_ground: None,
             up: Dir3::Y,
+            speed: EXAMPLE_MOVEMENT_SPEED,
+            ground_velocity: Vec3::ZERO,
+            platform_inheritance: PlatformInheritance::default(),
+            ground_snapping: GroundSnapping::default(),
+            slope_speed: SlopeSpeed::default(),
+            edges: EdgeHandling::default(),
             config: MoveAndSlideConfig::default(),
         }
     }
 }
 
+/// The surfaces supporting the character from below, found by the ground check while grounded.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct GroundContacts(pub Vec<GroundContact>);
+
+/// Entities the character's movement ignores, in addition to the layers it doesn't collide with.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct IgnoredEntities(pub Vec<Entity>);
+
 // Marker component used to freeze player movement when the main camera is in fly-mode.
 // This shouldn't be strictly necessary if we figure out how to properly layer InputContexts.
 #[derive(Component)]
 pub struct Frozen;
 
-fn jump_input(mut query: Query<(&mut Character, &Actions<DefaultContext>)>) {
-    for (mut character, actions) in &mut query {
-        if character.grounded() && actions.action::<Jump>().state() == ActionState::Fired {
-            character.jump(EXAMPLE_JUMP_IMPULSE);
+/// How far back the move input has to be held to drop through a one-way platform when jumping.
+const DROP_THROUGH_INPUT: f32 = -0.5;
+
+/// How far a character is moved into a one-way platform when dropping through it, so it's passing through.
+const DROP_THROUGH_DISTANCE: f32 = 0.05;
+
+pub(crate) fn jump_input(
+    mut query: Query<(
+        &mut Transform,
+        &mut Character,
+        &mut PassingThrough,
+        &Actions<DefaultContext>,
+    )>,
+    one_way_platforms: Query<(), With<OneWayPlatform>>,
+) {
+    for (mut transform, mut character, mut passing_through, actions) in &mut query {
+        let Some(ground) = character.ground else {
+            continue;
+        };
+
+        if actions.action::<Jump>().state() != ActionState::Fired {
+            continue;
+        }
+
+        // Drop through one-way platforms instead of jumping when holding back
+        let input_vec = actions.action::<input::Move>().value().as_axis2d();
+        if one_way_platforms.contains(ground.entity) && input_vec.y < DROP_THROUGH_INPUT {
+            transform.translation -= character.up * DROP_THROUGH_DISTANCE;
+            passing_through.0.push(ground.entity);
+            character.ground = None;
+            continue;
         }
+
+        character.jump(EXAMPLE_JUMP_IMPULSE);
     }
 }
 
 fn platform_movement(
     spatial_query: SpatialQuery,
-    mut query: Query<(&mut Transform, &mut Character, &Collider, &CharacterFilter)>,
-    platforms: Query<(&GlobalTransform, &PreviousGlobalTransform)>,
+    mut query: Query<(
+        &mut Transform,
+        &mut Character,
+        &mut PlatformYaw,
+        &MovementMode,
+        &Collider,
+        &CharacterFilter,
+    )>,
+    platforms: Query<(
+        &GlobalTransform,
+        &PreviousGlobalTransform,
+        Option<&LinearVelocity>,
+        Option<&AngularVelocity>,
+    )>,
     time: Res<Time>,
 ) {
-    for (mut transform, mut character, collider, filter) in &mut query {
-        let platform_motion = |entity| {
+    for (mut transform, mut character, mut platform_yaw, mode, collider, filter) in &mut query {
+        let up = character.up;
+
+        // Only the turn of this tick is kept, whether or not a camera reads it
+        platform_yaw.0 = 0.0;
+
+        // The platform moves the character where it touches the ground, at the bottom of the collider
+        let (half_height, _) = collider_extents(collider, transform.rotation, up);
+        let contact_offset = -up * half_height;
+
+        let platform_velocity = |entity, point| {
             platforms.get(entity).map_or(
-                Vec3::ZERO,
-                |(platform_transform, prev_platform_transform)| {
-                    motion_on_point(
-                        transform.translation,
+                (Vec3::ZERO, 0.0),
+                |(platform_transform, prev_platform_transform, linear, angular)| {
+                    platform_velocity(
+                        point,
+                        up,
                         platform_transform,
                         prev_platform_transform,
+                        linear,
+                        angular,
+                        time.delta_secs(),
                     )
                 },
             )
         };
 
+        // Catch airborne characters with platforms rising into them, before the platform moves
+        // this tick. Otherwise the platform overlaps the capsule and the character falls through.
+        if character.ground.is_none()
+            && matches!(*mode, MovementMode::Walking | MovementMode::Falling)
+        {
+            if let Some((safe_distance, hit)) = sweep_check(
+                collider,
+                character.config.epsilon,
+                transform.translation,
+                -up,
+                EXAMPLE_STEP_HEIGHT + EXAMPLE_GROUND_CHECK_DISTANCE,
+                transform.rotation,
+                &spatial_query,
+                &filter.0,
+            ) {
+                let (velocity, _) = platform_velocity(hit.entity, hit.point1);
+                let closing_speed = (velocity - character.velocity).dot(*up);
+
+                if let Some(ground) =
+                    Ground::new_if_walkable(hit.entity, hit.normal1, up, EXAMPLE_WALKABLE_ANGLE)
+                        .filter(|_| velocity.dot(*up) > 0.0)
+                        .filter(|_| {
+                            closing_speed * time.delta_secs() + EXAMPLE_GROUND_CHECK_DISTANCE
+                                >= safe_distance
+                        })
+                {
+                    // Stand on the platform and let it carry the character up
+                    transform.translation -= up * safe_distance;
+                    character.velocity = character.velocity.reject_from_normalized(*up);
+                    character.ground = Some(ground);
+                }
+            }
+        }
+
         match (character.ground, character.previous_ground) {
             // Currently on the platform, follow it's movement
             (Some(ground), ..) => {
-                let platform_motion = platform_motion(ground.entity);
+                let (velocity, yaw_velocity) =
+                    platform_velocity(ground.entity, transform.translation + contact_offset);
+                character.ground_velocity = velocity;
+                let platform_motion = velocity * time.delta_secs();
 
                 // Sweep in the platform movement direction to avoid passing through walls
                 if let Ok((direction, max_distance)) = Dir3::new_and_length(platform_motion) {
+                    // The platform itself hasn't moved yet, so ignore it when it's moving away
+                    // from the character, otherwise descending platforms leave it behind
+                    let mut sweep_filter = Cow::Borrowed(&filter.0);
+                    if direction.dot(*ground.normal) < 0.0 {
+                        sweep_filter
+                            .to_mut()
+                            .excluded_entities
+                            .insert(ground.entity);
+                    }
+
                     let safe_distance = sweep_check(
                         collider,
                         character.config.epsilon,
@@ -155,44 +372,128 @@ fn platform_movement(
                         max_distance,
                         transform.rotation,
                         &spatial_query,
-                        &filter.0,
+                        &sweep_filter,
                     )
                     .map(|(d, _)| d)
                     .unwrap_or(max_distance);
 
                     transform.translation += direction * safe_distance;
                 };
+
+                // Turn with the platform around the up axis
+                let yaw = yaw_velocity * time.delta_secs();
+                if yaw != 0.0 {
+                    transform.rotate_axis(up, yaw);
+                    platform_yaw.0 = yaw;
+                }
             }
-            // Left the platform, inherit the platform velocity
+            // Walked off the platform, inherit the platform velocity
             (None, Some(previous_ground)) => {
-                let platform_velocity = platform_motion(previous_ground.entity) / time.delta_secs();
-                character.velocity += platform_velocity;
+                let (velocity, _) = platform_velocity(
+                    previous_ground.entity,
+                    transform.translation + contact_offset,
+                );
+                character.velocity += character.platform_inheritance.apply(velocity, up);
+                character.ground_velocity = Vec3::ZERO;
             }
-            _ => {}
+            _ => character.ground_velocity = Vec3::ZERO,
         }
 
         character.previous_ground = character.ground;
     }
 }
 
-fn movement(
+/// Returns the velocity of a platform at the given `point` and its angular velocity around `up`.
+///
+/// Kinematic platforms moved by their [`LinearVelocity`] and [`AngularVelocity`] use those directly.
+/// Otherwise, e.g. when the platform is animated, the velocity is derived from the change in its transform.
+fn platform_velocity(
+    point: Vec3,
+    up: Dir3,
+    platform_transform: &GlobalTransform,
+    prev_platform_transform: &GlobalTransform,
+    linear: Option<&LinearVelocity>,
+    angular: Option<&AngularVelocity>,
+    delta: f32,
+) -> (Vec3, f32) {
+    let linear = linear.map_or(Vec3::ZERO, |velocity| velocity.0);
+    let angular = angular.map_or(Vec3::ZERO, |velocity| velocity.0);
+
+    if linear != Vec3::ZERO || angular != Vec3::ZERO {
+        let velocity = velocity_on_point(point, platform_transform.translation(), linear, angular);
+        return (velocity, angular.dot(*up));
+    }
+
+    if delta <= 0.0 {
+        return (Vec3::ZERO, 0.0);
+    }
+
+    let motion = motion_on_point(point, platform_transform, prev_platform_transform);
+    let yaw = rotation_on_axis(up, platform_transform, prev_platform_transform);
+
+    (motion / delta, yaw / delta)
+}
+
+pub(crate) fn movement(
     mut q_kcc: Query<
         (
             &Actions<DefaultContext>,
             &mut Transform,
             &mut Character,
+            &mut MovementMode,
+            &mut ExternalForces,
+            &mut GroundContacts,
+            &mut PassingThrough,
             &Collider,
             &CharacterFilter,
-            Has<Sensor>,
         ),
         Without<Frozen>,
     >,
+    one_way_platforms: Query<&GlobalTransform, With<OneWayPlatform>>,
+    collision_layers: Query<&CollisionLayers>,
+    surface_materials: Query<&SurfaceMaterial>,
+    surface_velocities: Query<(&SurfaceVelocity, &GlobalTransform)>,
     main_camera: Single<&Transform, (With<MainCamera>, Without<Character>)>,
     time: Res<Time>,
     spatial_query: SpatialQuery,
 ) {
     let main_camera_transform = main_camera.into_inner();
-    for (actions, mut transform, mut character, collider, filter, has_sensor) in &mut q_kcc {
+    for (
+        actions,
+        mut transform,
+        mut character,
+        mut mode,
+        mut forces,
+        mut contacts,
+        mut passing_through,
+        collider,
+        filter,
+    ) in &mut q_kcc
+    {
+        // Other modes are handled by their own systems
+        if !matches!(*mode, MovementMode::Walking | MovementMode::Falling) {
+            continue;
+        }
+
+        // Apply queued impulses and forces before anything else, they might clear the ground
+        if !forces.is_empty() {
+            forces.resolve(&mut character, time.delta_secs());
+        }
+
+        // Where the character stood, in case it tries to walk off of a ledge while crouched
+        let start_translation = transform.translation;
+
+        // One-way platforms are only solid when moving down onto their top face
+        let passes_one_way =
+            |entity, normal, velocity| passes_one_way(&one_way_platforms, entity, normal, velocity);
+
+        // Characters can't step up onto geometry on the no-step layer
+        let can_step_on = |entity| {
+            !collision_layers
+                .get(entity)
+                .is_ok_and(|layers| layers.memberships.has_all(GameLayer::NoStep))
+        };
+
         // Get the raw 2D input vector
         let input_vec = actions.action::<input::Move>().value().as_axis2d();
 
@@ -203,39 +504,55 @@ fn movement(
         // Rotate the movement direction vector by only the camera's yaw
         let direction = yaw_rotation * Vec3::new(input_vec.x, 0.0, -input_vec.y);
 
-        let max_acceleration = match character.ground {
-            Some(_) => {
-                let friction = friction(character.velocity, EXAMPLE_FRICTION, time.delta_secs());
+        // Ground movement is relative to the velocity of the surface, so conveyors carry the character along
+        let surface_velocity = character.ground.map_or(Vec3::ZERO, |ground| {
+            ground.surface_velocity(&surface_velocities)
+        });
+
+        let (max_acceleration, target_speed) = match character.ground {
+            Some(ground) => {
+                let material = ground.material(&surface_materials);
+
+                let friction = friction(
+                    character.velocity - surface_velocity,
+                    EXAMPLE_FRICTION * material.friction,
+                    time.delta_secs(),
+                );
                 character.velocity += friction;
 
-                EXAMPLE_GROUND_ACCELERATION
+                // Slippery surfaces pull the character down the slope
+                let slope_gravity = (character.up * -EXAMPLE_GRAVITY).reject_from(*ground.normal);
+                character.velocity += slope_gravity * material.slipperiness * time.delta_secs();
+
+                // Slow down uphill and speed up downhill
+                let slope =
+                    character
+                        .slope_speed
+                        .multiplier(direction, ground.normal, character.up);
+
+                (
+                    EXAMPLE_GROUND_ACCELERATION * material.acceleration,
+                    character.speed * material.max_speed * slope,
+                )
             }
             None => {
                 // Apply gravity when not grounded
                 let gravity = character.up * -EXAMPLE_GRAVITY * time.delta_secs();
                 character.velocity += gravity;
 
-                EXAMPLE_AIR_ACCELERATION
+                (EXAMPLE_AIR_ACCELERATION, character.speed)
             }
         };
 
         // accelerate in the movement direction
         let mut move_accel = acceleration(
-            character.velocity,
+            character.velocity - surface_velocity,
             direction,
             max_acceleration,
-            EXAMPLE_MOVEMENT_SPEED,
+            target_speed,
             time.delta_secs(),
         );
 
-        // We can skip everything if the character has a sensor component
-        if has_sensor {
-            character.velocity += move_accel;
-            transform.translation += character.velocity * time.delta_secs();
-
-            continue;
-        }
-
         // We need to store the new ground for the ground check to work properly
         let mut new_ground = None;
 
@@ -258,7 +575,9 @@ fn movement(
                 transform.rotation,
                 &spatial_query,
                 &filter.0,
-            ) {
+            )
+            .filter(|(_, hit)| !passes_one_way(hit.entity, hit.normal1, move_accel))
+            {
                 // Move to the hit point
                 transform.translation += direction * safe_distance;
 
@@ -272,19 +591,24 @@ fn movement(
 
                     // If the ground is walkable, project motion on ground plane
                     move_accel = project_motion_on_ground(move_accel, hit.normal1, character.up);
-                } else if let Some(step_result) = try_step_up_on_hit(
-                    collider,
-                    transform.translation,
-                    transform.rotation,
-                    character.up,
-                    hit.normal1,
-                    direction,
-                    max_distance - safe_distance,
-                    character.config.epsilon,
-                    &spatial_query,
-                    &filter.0,
-                    time.delta_secs(),
-                ) {
+                } else if let Some(step_result) = can_step_on(hit.entity)
+                    .then(|| {
+                        try_step_up_on_hit(
+                            collider,
+                            transform.translation,
+                            transform.rotation,
+                            character.up,
+                            hit.normal1,
+                            direction,
+                            max_distance - safe_distance,
+                            character.config.epsilon,
+                            &spatial_query,
+                            &filter.0,
+                            time.delta_secs(),
+                        )
+                    })
+                    .flatten()
+                {
                     new_ground = Some(step_result.ground);
 
                     // Step up
@@ -308,25 +632,32 @@ fn movement(
             &filter.0,
             time.delta_secs(),
             |hit| {
-                if let Some(ground) = Ground::new_if_walkable(
-                    hit.hit_data.entity,
-                    hit.hit_data.normal1,
+                if pass_through_one_way(hit, &one_way_platforms, &mut passing_through) {
+                    return false;
+                }
+
+                if let Some(ground) = resolve_ground(
+                    &spatial_query,
+                    &filter.0,
+                    collider,
+                    transform.rotation,
+                    &hit.hit_data,
+                    *hit.translation,
                     character.up,
                     EXAMPLE_WALKABLE_ANGLE,
+                    character.edges.perch_radius,
+                    character.ground_snapping.step_down_height,
                 ) {
                     new_ground = Some(ground);
 
                     // Avoid sliding down slopes when just landing
                     if !character.grounded() {
-                        *hit.velocity = project_motion_on_ground(
-                            *hit.velocity,
-                            hit.hit_data.normal1,
-                            character.up,
-                        );
+                        *hit.velocity =
+                            project_motion_on_ground(*hit.velocity, *ground.normal, character.up);
 
                         character.velocity = project_motion_on_ground(
                             character.velocity,
-                            hit.hit_data.normal1,
+                            *ground.normal,
                             character.up,
                         );
                     }
@@ -336,8 +667,8 @@ fn movement(
 
                 let grounded = character.grounded() || new_ground.is_some();
 
-                // In order to try step up we need to be grounded and hitting a "wall".
-                if grounded {
+                // In order to try step up we need to be grounded and hitting a "wall" we can step on.
+                if grounded && can_step_on(hit.hit_data.entity) {
                     if let Some(step_result) = try_step_up_on_hit(
                         collider,
                         *hit.translation,
@@ -392,8 +723,48 @@ fn movement(
 
         transform.translation = move_result.new_translation;
 
-        // Check if the previous ground is still there and snap to it
-        if character.grounded() {
+        contacts.0.clear();
+
+        // Check if the previous ground is still there and snap to it, stepping down onto lower ground
+        if let Some(previous_ground) = character.ground {
+            let snapping = character.ground_snapping;
+            if let Some((safe_distance, ground)) = ground_check(
+                &collider,
+                character.config,
+                transform.translation,
+                character.up,
+                transform.rotation,
+                &spatial_query,
+                &filter.0,
+                EXAMPLE_GROUND_CHECK_DISTANCE.max(snapping.step_down_height),
+                EXAMPLE_WALKABLE_ANGLE,
+                character.edges.perch_radius,
+                &mut contacts.0,
+            )
+            .filter(|(_, ground)| {
+                !passes_one_way(ground.entity, *ground.normal, character.velocity)
+            })
+            .filter(|(safe_distance, ground)| {
+                snapping.keeps_ground(
+                    character.velocity,
+                    *safe_distance,
+                    EXAMPLE_GROUND_CHECK_DISTANCE,
+                    &previous_ground,
+                    ground,
+                )
+            }) {
+                transform.translation -= character.up * safe_distance;
+                new_ground = Some(ground);
+
+                // Don't keep moving away from the ground after passing over a crest
+                if ground.normal != previous_ground.normal
+                    && character.velocity.dot(*ground.normal) > 0.0
+                {
+                    character.velocity = character.velocity.reject_from_normalized(*ground.normal);
+                }
+            }
+        } else if new_ground.is_none() && character.velocity.dot(*character.up) <= 0.0 {
+            // Land on support the sweeps don't find on their own, like two steep slopes facing each other
             if let Some((safe_distance, ground)) = ground_check(
                 &collider,
                 character.config,
@@ -404,8 +775,15 @@ fn movement(
                 &filter.0,
                 EXAMPLE_GROUND_CHECK_DISTANCE,
                 EXAMPLE_WALKABLE_ANGLE,
-            ) {
+                character.edges.perch_radius,
+                &mut contacts.0,
+            )
+            .filter(|(_, ground)| {
+                !passes_one_way(ground.entity, *ground.normal, character.velocity)
+            }) {
                 transform.translation -= character.up * safe_distance;
+                character.velocity =
+                    project_motion_on_ground(character.velocity, *ground.normal, character.up);
                 new_ground = Some(ground);
             }
         }
@@ -421,11 +799,142 @@ fn movement(
         let all = character.velocity.length();
         // dbg!([h, v, all]);
 
+        // Stay on the ledge instead of walking off of it while crouched, sliding along its edge
+        if let Some(previous_ground) = character.ground.filter(|_| {
+            new_ground.is_none()
+                && character.edges.protect_ledges_while_crouched
+                && actions.action::<Crouch>().state() == ActionState::Fired
+        }) {
+            if let Some(ledge) = clip_motion_to_ledge(
+                &spatial_query,
+                &filter.0,
+                collider,
+                character.config,
+                transform.rotation,
+                character.up,
+                start_translation,
+                transform.translation,
+                &previous_ground,
+                character.edges.perch_radius,
+                &mut contacts.0,
+            ) {
+                transform.translation = ledge.translation;
+                new_ground = Some(ledge.ground);
+
+                // Stop moving off of the ledge
+                let up = character.up;
+                character.velocity = match ledge.edge_normal {
+                    Some(normal) => {
+                        let outwards = character.velocity.dot(*normal).max(0.0);
+                        character.velocity - normal * outwards
+                    }
+                    None => character.velocity.project_onto_normalized(*up),
+                };
+            }
+        }
+
+        // Only keep the contacts of the ground the character stands on
+        if new_ground.is_none() {
+            contacts.0.clear();
+        }
+
         // Update the ground
         character.ground = new_ground;
+
+        mode.set_if_neq(match character.grounded() {
+            true => MovementMode::Walking,
+            false => MovementMode::Falling,
+        });
     }
 }
 
+struct LedgeResult {
+    translation: Vec3,
+    ground: Ground,
+    /// Horizontal normal of the side of the ledge, `None` if the character couldn't move at all.
+    edge_normal: Option<Dir3>,
+}
+
+/// Clip the horizontal motion from `start` to `end` that leaves the `ground`, keeping the motion along the edge
+/// of the ledge.
+///
+/// Returns `None` if there's no ground even without moving, e.g. when a platform moved out from under the character.
+fn clip_motion_to_ledge(
+    spatial_query: &SpatialQuery,
+    filter: &SpatialQueryFilter,
+    collider: &Collider,
+    config: MoveAndSlideConfig,
+    rotation: Quat,
+    up: Dir3,
+    start: Vec3,
+    end: Vec3,
+    ground: &Ground,
+    perch_radius: f32,
+    contacts: &mut Vec<GroundContact>,
+) -> Option<LedgeResult> {
+    let horizontal = (end - start).reject_from_normalized(*up);
+    let (half_height, radius) = collider_extents(collider, rotation, up);
+
+    // Raycast back towards the start just below the ground to find the side of the ledge
+    let edge_normal = Dir3::new(-horizontal)
+        .ok()
+        .and_then(|back| {
+            let origin = end - up * (half_height + EXAMPLE_GROUND_CHECK_DISTANCE);
+            spatial_query.cast_ray(origin, back, horizontal.length() + radius, true, filter)
+        })
+        .and_then(|hit| Dir3::new(hit.normal.reject_from_normalized(*up)).ok());
+
+    // Slide along the edge, or stay in place if that doesn't keep the character on the ledge either
+    let along_edge = edge_normal.map(|normal| {
+        let motion = horizontal - normal * horizontal.dot(*normal).max(0.0);
+        (motion, Some(normal))
+    });
+
+    for (motion, edge_normal) in along_edge.into_iter().chain([(Vec3::ZERO, None)]) {
+        let mut translation = start;
+
+        // Move along the ground, without running into anything move and slide avoided
+        let motion = project_motion_on_ground(motion, *ground.normal, up);
+        if let Ok((direction, max_distance)) = Dir3::new_and_length(motion) {
+            let distance = sweep_check(
+                collider,
+                config.epsilon,
+                start,
+                direction,
+                max_distance,
+                rotation,
+                spatial_query,
+                filter,
+            )
+            .map_or(max_distance, |(distance, _)| distance);
+
+            translation += direction * distance;
+        }
+
+        if let Some((safe_distance, ground)) = ground_check(
+            collider,
+            config,
+            translation,
+            up,
+            rotation,
+            spatial_query,
+            filter,
+            EXAMPLE_GROUND_CHECK_DISTANCE,
+            EXAMPLE_WALKABLE_ANGLE,
+            perch_radius,
+            contacts,
+        ) {
+            return Some(LedgeResult {
+                translation: translation - up * safe_distance,
+                ground,
+                edge_normal,
+            });
+        }
+    }
+
+    None
+}
+
 struct StepUpResult {
     translation: Vec3,
     move_time: f32,
@@ -501,7 +1010,7 @@ fn try_step_up_on_hit(
 
 /// This is a simple example inspired by Quake, users are expected to bring their own logic for acceleration.
 #[must_use]
-fn acceleration(
+pub(crate) fn acceleration(
     velocity: Vec3,
     direction: impl TryInto<Dir3>,
     max_acceleration: f32,
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+
+pub(crate) fn plugin(app: &mut App) {
+    app.configure_sets(
+        FixedUpdate,
+        (MovementSet::Update, MovementSet::Transitions).chain(),
+    );
+    app.configure_sets(
+        FixedUpdate,
+        (
+            DetectionSet::Swimming,
+            DetectionSet::Climbing,
+            DetectionSet::LedgeGrab,
+            DetectionSet::WallRun,
+            DetectionSet::Sliding,
+            DetectionSet::CrouchSlide,
+        )
+            .chain()
+            .in_set(MovementSet::Update),
+    );
+    app.add_systems(
+        FixedUpdate,
+        dispatch_movement_mode_transitions.in_set(MovementSet::Transitions),
+    );
+}
+
+/// System sets for character movement, run in [`FixedUpdate`].
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MovementSet {
+    /// Per-mode movement logic, every system in this set is expected to only
+    /// update characters in the [`MovementMode`] it is responsible for.
+    Update,
+    /// Mode changes made during [`MovementSet::Update`] are dispatched as
+    /// [`ExitMovementMode`] and [`EnterMovementMode`] events.
+    Transitions,
+}
+
+/// Systems that switch characters from [`MovementMode::Walking`] or [`MovementMode::Falling`] into another mode,
+/// ordered by priority inside [`MovementSet::Update`].
+///
+/// Earlier sets run first, so they win when several modes could start on the same tick.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DetectionSet {
+    Swimming,
+    Climbing,
+    LedgeGrab,
+    WallRun,
+    Sliding,
+    CrouchSlide,
+}
+
+/// The current movement mode of a character.
+///
+/// Set this to transition between modes, the previous mode receives an [`ExitMovementMode`] event
+/// and the new mode an [`EnterMovementMode`] event on the character entity.
+#[derive(Component, Reflect, Default, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[reflect(Component)]
+#[require(PreviousMovementMode)]
+pub enum MovementMode {
+    /// Standing on walkable ground.
+    Walking,
+    /// Airborne and affected by gravity.
+    #[default]
+    Falling,
+    /// Sliding down a slope.
+    Sliding,
+    /// Moving through water.
+    Swimming,
+    /// Attached to a climbable surface.
+    Climbing,
+    /// Flying freely through geometry.
+    Noclip,
+    /// Any other mode, identified by name.
+    Custom(&'static str),
+}
+
+/// Triggered on a character entity when it enters a [`MovementMode`].
+#[derive(Event, Debug, Clone, Copy)]
+pub struct EnterMovementMode(pub MovementMode);
+
+/// Triggered on a character entity when it exits a [`MovementMode`].
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ExitMovementMode(pub MovementMode);
+
+/// The mode of the character the last time transitions were dispatched.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+struct PreviousMovementMode(MovementMode);
+
+/// Run condition that returns `true` if any character is in the given `mode`.
+pub fn any_in_movement_mode(
+    mode: MovementMode,
+) -> impl FnMut(Query<&MovementMode>) -> bool + Clone {
+    move |query: Query<&MovementMode>| query.iter().any(|current| *current == mode)
+}
+
+fn dispatch_movement_mode_transitions(
+    mut commands: Commands,
+    mut query: Query<(Entity, &MovementMode, &mut PreviousMovementMode), Changed<MovementMode>>,
+) {
+    for (entity, mode, mut previous) in &mut query {
+        if previous.0 == *mode {
+            continue;
+        }
+
+        commands.trigger_targets(ExitMovementMode(previous.0), entity);
+        commands.trigger_targets(EnterMovementMode(*mode), entity);
+
+        previous.0 = *mode;
+    }
+}
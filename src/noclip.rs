@@ -0,0 +1,198 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::{Actions, Fired};
+
+use crate::{
+    camera::MainCamera,
+    input::{self, AdjustNoclipSpeed, DefaultContext, NoclipFly, ToggleNoclip},
+    movement::{Character, CharacterFilter, Frozen, movement},
+    movement_mode::{MovementMode, MovementSet},
+};
+
+/// Lets characters fly freely through geometry, mainly for repositioning them while debugging.
+pub struct NoclipPlugin;
+
+impl Plugin for NoclipPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_required_components::<Character, NoclipConfig>();
+        app.add_systems(
+            FixedUpdate,
+            noclip_update.after(movement).in_set(MovementSet::Update),
+        );
+        app.add_systems(Update, noclip_speed_input);
+        app.add_observer(toggle_noclip);
+    }
+}
+
+/// Configuration of the noclip mode.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct NoclipConfig {
+    /// Current flying speed, adjusted at runtime with [`AdjustNoclipSpeed`].
+    pub speed: f32,
+    pub min_speed: f32,
+    pub max_speed: f32,
+    /// How fast the speed is scaled while adjusting it, as a factor per second.
+    pub speed_change_rate: f32,
+    /// Distance between the candidate positions tested when looking for a free spot on exit.
+    pub resolve_step: f32,
+    /// How far from the current position to look for a free spot on exit.
+    pub resolve_max_distance: f32,
+}
+
+impl Default for NoclipConfig {
+    fn default() -> Self {
+        Self {
+            speed: 10.0,
+            min_speed: 1.0,
+            max_speed: 100.0,
+            speed_change_rate: 2.0,
+            resolve_step: 0.25,
+            resolve_max_distance: 5.0,
+        }
+    }
+}
+
+/// Directions tested when looking for a free spot, up first since that's the most likely way out of the floor.
+const RESOLVE_DIRECTIONS: [Vec3; 10] = [
+    Vec3::Y,
+    Vec3::X,
+    Vec3::NEG_X,
+    Vec3::Z,
+    Vec3::NEG_Z,
+    Vec3::new(1.0, 1.0, 0.0),
+    Vec3::new(-1.0, 1.0, 0.0),
+    Vec3::new(0.0, 1.0, 1.0),
+    Vec3::new(0.0, 1.0, -1.0),
+    Vec3::NEG_Y,
+];
+
+/// Returns the closest position to `translation` where the `collider` doesn't overlap anything.
+///
+/// Candidates are tested at increasing distances along [`RESOLVE_DIRECTIONS`].
+pub fn find_free_position(
+    spatial_query: &SpatialQuery,
+    collider: &Collider,
+    translation: Vec3,
+    rotation: Quat,
+    step: f32,
+    max_distance: f32,
+    filter: &SpatialQueryFilter,
+) -> Option<Vec3> {
+    let is_free = |position: Vec3| {
+        spatial_query
+            .shape_intersections(collider, position, rotation, filter)
+            .is_empty()
+    };
+
+    if is_free(translation) {
+        return Some(translation);
+    }
+
+    let steps = (max_distance / step).ceil() as u32;
+    (1..=steps).find_map(|i| {
+        let distance = i as f32 * step;
+        RESOLVE_DIRECTIONS
+            .iter()
+            .map(|direction| translation + direction.normalize() * distance)
+            .find(|position| is_free(*position))
+    })
+}
+
+fn noclip_update(
+    mut query: Query<
+        (
+            &Actions<DefaultContext>,
+            &mut Transform,
+            &mut Character,
+            &MovementMode,
+            &NoclipConfig,
+        ),
+        Without<Frozen>,
+    >,
+    main_camera: Single<&Transform, (With<MainCamera>, Without<Character>)>,
+    time: Res<Time>,
+) {
+    let main_camera_transform = main_camera.into_inner();
+    for (actions, mut transform, mut character, mode, config) in &mut query {
+        if *mode != MovementMode::Noclip {
+            continue;
+        }
+
+        let move_input = actions.action::<input::Move>().value().as_axis2d();
+        let fly_input = actions.action::<NoclipFly>().value().as_axis1d();
+
+        // Fly in the look direction of the camera, straight through any geometry
+        let direction = main_camera_transform.rotation
+            * Vec3::new(move_input.x, 0.0, -move_input.y)
+            + character.up * fly_input;
+
+        character.velocity = direction.clamp_length_max(1.0) * config.speed;
+        character.ground = None;
+
+        transform.translation += character.velocity * time.delta_secs();
+    }
+}
+
+fn noclip_speed_input(
+    mut query: Query<(&Actions<DefaultContext>, &MovementMode, &mut NoclipConfig)>,
+    time: Res<Time>,
+) {
+    for (actions, mode, mut config) in &mut query {
+        let input = actions.action::<AdjustNoclipSpeed>().value().as_axis1d();
+
+        if *mode != MovementMode::Noclip || input == 0.0 {
+            continue;
+        }
+
+        let scale = config.speed_change_rate.powf(input * time.delta_secs());
+        config.speed = (config.speed * scale).clamp(config.min_speed, config.max_speed);
+    }
+}
+
+fn toggle_noclip(
+    trigger: Trigger<Fired<ToggleNoclip>>,
+    mut query: Query<
+        (
+            &mut Transform,
+            &mut Character,
+            &mut MovementMode,
+            &Collider,
+            &CharacterFilter,
+            &NoclipConfig,
+        ),
+        Without<Frozen>,
+    >,
+    spatial_query: SpatialQuery,
+) {
+    let Ok((mut transform, mut character, mut mode, collider, filter, config)) =
+        query.get_mut(trigger.target())
+    else {
+        return;
+    };
+
+    if *mode != MovementMode::Noclip {
+        *mode = MovementMode::Noclip;
+        return;
+    }
+
+    // Resolve the character's placement before handing it back to regular movement
+    match find_free_position(
+        &spatial_query,
+        collider,
+        transform.translation,
+        transform.rotation,
+        config.resolve_step,
+        config.resolve_max_distance,
+        &filter.0,
+    ) {
+        Some(translation) => transform.translation = translation,
+        None => {
+            warn!("No free spot found to exit noclip, staying in noclip");
+            return;
+        }
+    }
+
+    character.velocity = Vec3::ZERO;
+    *mode = MovementMode::Falling;
+}
@@ -0,0 +1,309 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::Actions;
+
+use crate::{
+    camera::MainCamera,
+    character::*,
+    input::{self, DefaultContext},
+    move_and_slide::*,
+    movement::{Character, CharacterFilter, Frozen, friction, movement},
+    movement_mode::{DetectionSet, ExitMovementMode, MovementMode, MovementSet},
+};
+
+/// Lets characters slide down slopes that are too steep to walk on.
+pub struct SlidingPlugin;
+
+impl Plugin for SlidingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_required_components::<Character, SlidingConfig>();
+        app.add_systems(
+            FixedUpdate,
+            (
+                sliding_update,
+                sliding_detection.in_set(DetectionSet::Sliding),
+            )
+                .chain()
+                .after(movement)
+                .in_set(MovementSet::Update),
+        );
+        app.add_observer(exit_sliding);
+    }
+}
+
+/// Configuration of how a character slides down steep slopes.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct SlidingConfig {
+    /// Fraction of gravity accelerating the character down the slope.
+    pub gravity_scale: f32,
+    /// Constant deceleration while sliding.
+    pub friction: f32,
+    /// The slide speed is clamped to this.
+    pub max_speed: f32,
+    /// Acceleration across the slope in the input direction, the character can't steer up the slope.
+    pub steering_acceleration: f32,
+    /// Slopes steeper than this are treated as walls, in radians.
+    pub max_slope_angle: f32,
+    /// How far below the character to look for the slope.
+    pub ground_distance: f32,
+}
+
+impl Default for SlidingConfig {
+    fn default() -> Self {
+        Self {
+            gravity_scale: 1.0,
+            friction: 2.0,
+            max_speed: 20.0,
+            steering_acceleration: 10.0,
+            max_slope_angle: 85f32.to_radians(),
+            ground_distance: 0.2,
+        }
+    }
+}
+
+/// Inserted on a character while it's sliding down a slope.
+///
+/// The character is in [`MovementMode::Sliding`] while this component is present.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct Sliding {
+    /// The entity of the slope.
+    pub entity: Entity,
+    /// The normal of the slope.
+    pub normal: Dir3,
+}
+
+/// Sweep down and return the safe distance and the hit below the character.
+fn find_surface(
+    spatial_query: &SpatialQuery,
+    collider: &Collider,
+    translation: Vec3,
+    rotation: Quat,
+    up: Dir3,
+    distance: f32,
+    epsilon: f32,
+    filter: &SpatialQueryFilter,
+) -> Option<(f32, Entity, Dir3)> {
+    let (safe_distance, hit) = sweep_check(
+        collider,
+        epsilon,
+        translation,
+        -up,
+        distance,
+        rotation,
+        spatial_query,
+        filter,
+    )?;
+
+    let normal = Dir3::new(hit.normal1).ok()?;
+
+    Some((safe_distance, hit.entity, normal))
+}
+
+/// Returns `true` if the `normal` is too steep to walk on but not steep enough to be a wall.
+fn is_slidable(normal: Dir3, up: Dir3, config: &SlidingConfig) -> bool {
+    !is_walkable(*normal, up, EXAMPLE_WALKABLE_ANGLE)
+        && up.angle_between(*normal) < config.max_slope_angle
+}
+
+fn sliding_detection(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Character,
+            &mut MovementMode,
+            &Collider,
+            &CharacterFilter,
+            &SlidingConfig,
+        ),
+        Without<Frozen>,
+    >,
+    spatial_query: SpatialQuery,
+) {
+    for (entity, transform, mut character, mut mode, collider, filter, config) in &mut query {
+        if !matches!(*mode, MovementMode::Walking | MovementMode::Falling) || character.grounded() {
+            continue;
+        }
+
+        // Don't catch characters jumping up along a slope
+        let up = character.up;
+        if character.velocity.dot(*up) > 0.0 {
+            continue;
+        }
+
+        let Some((_, slope, normal)) = find_surface(
+            &spatial_query,
+            collider,
+            transform.translation,
+            transform.rotation,
+            up,
+            EXAMPLE_GROUND_CHECK_DISTANCE,
+            character.config.epsilon,
+            &filter.0,
+        ) else {
+            continue;
+        };
+
+        if !is_slidable(normal, up, config) {
+            continue;
+        }
+
+        // Keep the velocity along the slope
+        character.velocity = character.velocity.reject_from_normalized(*normal);
+        *mode = MovementMode::Sliding;
+
+        commands.entity(entity).insert(Sliding {
+            entity: slope,
+            normal,
+        });
+    }
+}
+
+fn sliding_update(
+    mut query: Query<
+        (
+            &Actions<DefaultContext>,
+            &mut Transform,
+            &mut Character,
+            &mut MovementMode,
+            &mut Sliding,
+            &mut PassingThrough,
+            &Collider,
+            &CharacterFilter,
+            &SlidingConfig,
+        ),
+        Without<Frozen>,
+    >,
+    main_camera: Single<&Transform, (With<MainCamera>, Without<Character>)>,
+    one_way_platforms: Query<&GlobalTransform, With<OneWayPlatform>>,
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+) {
+    let main_camera_transform = main_camera.into_inner();
+    for (
+        actions,
+        mut transform,
+        mut character,
+        mut mode,
+        mut sliding,
+        mut passing_through,
+        collider,
+        filter,
+        config,
+    ) in &mut query
+    {
+        if *mode != MovementMode::Sliding {
+            continue;
+        }
+
+        let up = character.up;
+
+        // Make sure the slope is still below the character
+        let Some((safe_distance, slope, normal)) = find_surface(
+            &spatial_query,
+            collider,
+            transform.translation,
+            transform.rotation,
+            up,
+            config.ground_distance,
+            character.config.epsilon,
+            &filter.0,
+        ) else {
+            *mode = MovementMode::Falling;
+            continue;
+        };
+
+        // Reached walkable ground
+        if let Some(ground) = Ground::new_if_walkable(slope, normal, up, EXAMPLE_WALKABLE_ANGLE) {
+            transform.translation -= up * safe_distance;
+            character.velocity = project_motion_on_ground(character.velocity, normal, up);
+            character.ground = Some(ground);
+            *mode = MovementMode::Walking;
+            continue;
+        }
+
+        if !is_slidable(normal, up, config) {
+            *mode = MovementMode::Falling;
+            continue;
+        }
+
+        // Stay on the slope instead of hopping off of it every tick
+        transform.translation -= up * safe_distance;
+        sliding.entity = slope;
+        sliding.normal = normal;
+
+        character.velocity = character.velocity.reject_from_normalized(*normal);
+
+        // Accelerate down the slope
+        let slope_gravity = (up * -EXAMPLE_GRAVITY).reject_from_normalized(*normal);
+        character.velocity += slope_gravity * config.gravity_scale * time.delta_secs();
+
+        let friction = friction(character.velocity, config.friction, time.delta_secs());
+        character.velocity += friction;
+
+        // Steer across the slope in the input direction
+        let input_vec = actions.action::<input::Move>().value().as_axis2d();
+        let camera_yaw = main_camera_transform.rotation.to_euler(EulerRot::YXZ).0;
+        let yaw_rotation = Quat::from_rotation_y(camera_yaw);
+        let direction = yaw_rotation * Vec3::new(input_vec.x, 0.0, -input_vec.y);
+
+        if let Ok(downhill) = Dir3::new(slope_gravity) {
+            let across = direction
+                .reject_from_normalized(*normal)
+                .reject_from_normalized(*downhill)
+                .normalize_or_zero();
+            character.velocity += across * config.steering_acceleration * time.delta_secs();
+        }
+
+        character.velocity = character.velocity.clamp_length_max(config.max_speed);
+
+        let mut new_ground = None;
+
+        let move_result = move_and_slide(
+            &spatial_query,
+            collider,
+            transform.translation,
+            character.velocity,
+            transform.rotation,
+            character.config,
+            &filter.0,
+            time.delta_secs(),
+            |hit| {
+                if pass_through_one_way(hit, &one_way_platforms, &mut passing_through) {
+                    return false;
+                }
+
+                if let Some(ground) = Ground::new_if_walkable(
+                    hit.hit_data.entity,
+                    hit.hit_data.normal1,
+                    up,
+                    EXAMPLE_WALKABLE_ANGLE,
+                ) {
+                    new_ground = Some(ground);
+                }
+
+                character.velocity = character.velocity.reject_from(hit.hit_data.normal1);
+
+                true
+            },
+        );
+
+        transform.translation = move_result.new_translation;
+
+        // Slid onto walkable ground
+        if let Some(ground) = new_ground {
+            character.velocity = project_motion_on_ground(character.velocity, *ground.normal, up);
+            character.ground = Some(ground);
+            *mode = MovementMode::Walking;
+        }
+    }
+}
+
+fn exit_sliding(trigger: Trigger<ExitMovementMode>, mut commands: Commands) {
+    if trigger.0 == MovementMode::Sliding {
+        commands.entity(trigger.target()).remove::<Sliding>();
+    }
+}
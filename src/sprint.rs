@@ -0,0 +1,181 @@
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::{ActionState, Actions};
+
+use crate::{
+    Attachments,
+    camera::SprintFov,
+    input::{self, DefaultContext, Sprint},
+    movement::{Character, Frozen, movement},
+    movement_mode::{MovementMode, MovementSet},
+};
+
+/// Lets grounded characters sprint forward while they have stamina left.
+pub struct SprintPlugin;
+
+impl Plugin for SprintPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_required_components::<Character, SprintConfig>();
+        app.register_required_components::<Character, Stamina>();
+        app.add_systems(
+            FixedUpdate,
+            sprint_update.before(movement).in_set(MovementSet::Update),
+        );
+        app.add_observer(start_sprint_fov);
+        app.add_observer(end_sprint_fov);
+    }
+}
+
+/// Configuration of how a character sprints.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct SprintConfig {
+    /// The target speed while sprinting, the character accelerates towards it like it would for regular movement.
+    pub speed: f32,
+    /// The maximum angle between the move input and the camera forward direction, in radians.
+    pub max_forward_angle: f32,
+    /// Stamina drained per second while sprinting.
+    pub drain_rate: f32,
+    /// Stamina regenerated per second while not sprinting.
+    pub regen_rate: f32,
+    /// The stamina needed to start sprinting, prevents stuttering sprints on an empty tank.
+    pub min_start_stamina: f32,
+}
+
+impl Default for SprintConfig {
+    fn default() -> Self {
+        Self {
+            speed: 12.0,
+            max_forward_angle: 50_f32.to_radians(),
+            drain_rate: 1.0,
+            regen_rate: 0.5,
+            min_start_stamina: 1.0,
+        }
+    }
+}
+
+/// The stamina of a character, drained by sprinting.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct Stamina {
+    pub current: f32,
+    pub max: f32,
+}
+
+impl Default for Stamina {
+    fn default() -> Self {
+        Self {
+            current: 5.0,
+            max: 5.0,
+        }
+    }
+}
+
+/// Inserted on a character while it's sprinting.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct Sprinting {
+    /// The speed of the character before it started sprinting, restored when the sprint ends.
+    pub previous_speed: f32,
+}
+
+/// Triggered on a character entity when it starts sprinting.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SprintStarted;
+
+/// Triggered on a character entity when it stops sprinting.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SprintEnded;
+
+fn sprint_update(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &Actions<DefaultContext>,
+        &mut Character,
+        &mut Stamina,
+        &MovementMode,
+        &SprintConfig,
+        Option<&Sprinting>,
+        Has<Frozen>,
+    )>,
+    time: Res<Time>,
+) {
+    for (entity, actions, mut character, mut stamina, mode, config, sprinting, frozen) in &mut query
+    {
+        let input_vec = actions.action::<input::Move>().value().as_axis2d();
+
+        // The move input is relative to the camera yaw, so forward input is forward relative to the camera
+        let forward = input_vec != Vec2::ZERO
+            && Vec2::Y.angle_to(input_vec).abs() <= config.max_forward_angle;
+
+        // Sprints only work on the ground, jumping ends them but keeps the momentum.
+        // Frozen characters stop sprinting too, restoring their speed and camera FOV
+        let can_sprint = !frozen
+            && *mode == MovementMode::Walking
+            && match sprinting {
+                Some(_) => stamina.current > 0.0,
+                None => stamina.current >= config.min_start_stamina,
+            };
+
+        let sprint =
+            can_sprint && forward && actions.action::<Sprint>().state() == ActionState::Fired;
+
+        match sprint {
+            true => {
+                stamina.current =
+                    (stamina.current - config.drain_rate * time.delta_secs()).max(0.0);
+            }
+            false => {
+                stamina.current =
+                    (stamina.current + config.regen_rate * time.delta_secs()).min(stamina.max);
+            }
+        }
+
+        // Only change the speed when the sprint starts or ends, so other speed modifiers aren't overwritten
+        match (sprint, sprinting) {
+            (true, None) => {
+                commands.entity(entity).insert(Sprinting {
+                    previous_speed: character.speed,
+                });
+                commands.trigger_targets(SprintStarted, entity);
+                character.speed = config.speed;
+            }
+            (false, Some(sprinting)) => {
+                commands.entity(entity).remove::<Sprinting>();
+                commands.trigger_targets(SprintEnded, entity);
+                character.speed = sprinting.previous_speed;
+            }
+            _ => {}
+        }
+    }
+}
+
+fn start_sprint_fov(
+    trigger: Trigger<SprintStarted>,
+    query: Query<&Attachments>,
+    mut cameras: Query<&mut SprintFov>,
+) -> Result {
+    let attachments = query.get(trigger.target())?;
+
+    let mut iter = cameras.iter_many_mut(attachments.iter());
+    while let Some(mut fov) = iter.fetch_next() {
+        fov.active = true;
+    }
+
+    Ok(())
+}
+
+fn end_sprint_fov(
+    trigger: Trigger<SprintEnded>,
+    query: Query<&Attachments>,
+    mut cameras: Query<&mut SprintFov>,
+) -> Result {
+    let attachments = query.get(trigger.target())?;
+
+    let mut iter = cameras.iter_many_mut(attachments.iter());
+    while let Some(mut fov) = iter.fetch_next() {
+        fov.active = false;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,274 @@
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::{ActionState, Actions};
+
+use crate::{
+    camera::MainCamera,
+    character::*,
+    input::{self, DefaultContext, Jump},
+    move_and_slide::*,
+    movement::{Character, CharacterFilter, Frozen, acceleration, friction, movement},
+    movement_mode::{DetectionSet, ExitMovementMode, MovementMode, MovementSet},
+};
+
+/// Lets characters swim inside [`Water`] volumes.
+pub struct SwimmingPlugin;
+
+impl Plugin for SwimmingPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_required_components::<Character, SwimmingConfig>();
+        app.add_systems(
+            FixedUpdate,
+            (swim_update, water_detection.in_set(DetectionSet::Swimming))
+                .chain()
+                .after(movement)
+                .in_set(MovementSet::Update),
+        );
+        app.add_systems(Update, swim_jump_input);
+        app.add_observer(exit_swimming);
+    }
+}
+
+/// Marks a sensor collider as a body of water.
+///
+/// The water surface is the top of the collider's [`ColliderAabb`], so volumes are expected to be axis-aligned.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+#[require(Sensor)]
+pub struct Water;
+
+/// Configuration of how a character behaves in water.
+///
+/// Depths are measured from the water surface down to the character's center.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct SwimmingConfig {
+    /// Start swimming when the character is deeper than this.
+    pub enter_depth: f32,
+    /// Stop swimming when the character is shallower than this.
+    pub exit_depth: f32,
+    /// The depth at which buoyancy cancels out gravity.
+    pub float_depth: f32,
+    /// The maximum buoyancy as a multiple of gravity, reached when deeper than the float depth.
+    pub max_buoyancy: f32,
+    /// The character is treading water when within this distance of the float depth.
+    pub tread_tolerance: f32,
+    pub speed: f32,
+    pub acceleration: f32,
+    /// Constant deceleration applied to the character's velocity while swimming.
+    pub drag: f32,
+    /// Impulse along `up` when jumping out of the water.
+    pub jump_out_impulse: f32,
+}
+
+impl Default for SwimmingConfig {
+    fn default() -> Self {
+        Self {
+            enter_depth: 0.5,
+            exit_depth: 0.1,
+            float_depth: 0.4,
+            max_buoyancy: 1.5,
+            tread_tolerance: 0.2,
+            speed: 4.0,
+            acceleration: 20.0,
+            drag: 6.0,
+            jump_out_impulse: 8.0,
+        }
+    }
+}
+
+/// Inserted on a character while it's swimming.
+///
+/// The character is in [`MovementMode::Swimming`] while this component is present.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct Swimming {
+    /// The entity of the water volume.
+    pub water: Entity,
+    /// Distance from the water surface down to the character's center.
+    pub depth: f32,
+    /// Whether the character is floating at the surface and is able to jump out.
+    pub treading: bool,
+}
+
+/// Returns the water volume at the given `point` along with the depth of the `point` below the water surface.
+pub fn water_depth(
+    spatial_query: &SpatialQuery,
+    point: Vec3,
+    up: Dir3,
+    waters: &Query<&ColliderAabb, With<Water>>,
+) -> Option<(Entity, f32)> {
+    spatial_query
+        .point_intersections(point, &SpatialQueryFilter::default())
+        .into_iter()
+        .filter_map(|entity| {
+            let aabb = waters.get(entity).ok()?;
+            Some((entity, aabb.max.dot(*up) - point.dot(*up)))
+        })
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+}
+
+fn water_detection(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Character,
+            &mut MovementMode,
+            &SwimmingConfig,
+        ),
+        Without<Frozen>,
+    >,
+    waters: Query<&ColliderAabb, With<Water>>,
+    spatial_query: SpatialQuery,
+) {
+    for (entity, transform, mut character, mut mode, config) in &mut query {
+        if !matches!(*mode, MovementMode::Walking | MovementMode::Falling) {
+            continue;
+        }
+
+        let Some((water, depth)) =
+            water_depth(&spatial_query, transform.translation, character.up, &waters)
+        else {
+            continue;
+        };
+
+        if depth < config.enter_depth {
+            continue;
+        }
+
+        character.ground = None;
+        *mode = MovementMode::Swimming;
+
+        commands.entity(entity).insert(Swimming {
+            water,
+            depth,
+            treading: false,
+        });
+    }
+}
+
+fn swim_update(
+    mut query: Query<
+        (
+            &Actions<DefaultContext>,
+            &mut Transform,
+            &mut Character,
+            &mut MovementMode,
+            &mut Swimming,
+            &mut PassingThrough,
+            &Collider,
+            &CharacterFilter,
+            &SwimmingConfig,
+        ),
+        Without<Frozen>,
+    >,
+    waters: Query<&ColliderAabb, With<Water>>,
+    main_camera: Single<&Transform, (With<MainCamera>, Without<Character>)>,
+    one_way_platforms: Query<&GlobalTransform, With<OneWayPlatform>>,
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+) {
+    let main_camera_transform = main_camera.into_inner();
+    for (
+        actions,
+        mut transform,
+        mut character,
+        mut mode,
+        mut swimming,
+        mut passing_through,
+        collider,
+        filter,
+        config,
+    ) in &mut query
+    {
+        if *mode != MovementMode::Swimming {
+            continue;
+        }
+
+        let up = character.up;
+
+        // Left the water or reached the shallows
+        let Some((water, depth)) = water_depth(&spatial_query, transform.translation, up, &waters)
+            .filter(|(_, depth)| *depth >= config.exit_depth)
+        else {
+            *mode = MovementMode::Falling;
+            continue;
+        };
+
+        *swimming = Swimming {
+            water,
+            depth,
+            treading: (depth - config.float_depth).abs() <= config.tread_tolerance,
+        };
+
+        // Swim in the look direction of the camera
+        let input_vec = actions.action::<input::Move>().value().as_axis2d();
+        let direction = main_camera_transform.rotation * Vec3::new(input_vec.x, 0.0, -input_vec.y);
+
+        // Buoyancy balances out gravity at the float depth
+        let buoyancy = (depth / config.float_depth).clamp(0.0, config.max_buoyancy);
+        character.velocity += up * EXAMPLE_GRAVITY * (buoyancy - 1.0) * time.delta_secs();
+
+        let drag = friction(character.velocity, config.drag, time.delta_secs());
+        character.velocity += drag;
+
+        let move_accel = acceleration(
+            character.velocity,
+            direction,
+            config.acceleration,
+            config.speed,
+            time.delta_secs(),
+        );
+        character.velocity += move_accel;
+
+        let move_result = move_and_slide(
+            &spatial_query,
+            collider,
+            transform.translation,
+            character.velocity,
+            transform.rotation,
+            character.config,
+            &filter.0,
+            time.delta_secs(),
+            |hit| {
+                if pass_through_one_way(hit, &one_way_platforms, &mut passing_through) {
+                    return false;
+                }
+
+                character.velocity = character.velocity.reject_from(hit.hit_data.normal1);
+
+                true
+            },
+        );
+
+        transform.translation = move_result.new_translation;
+    }
+}
+
+fn swim_jump_input(
+    mut query: Query<(
+        &mut Character,
+        &mut MovementMode,
+        &Swimming,
+        &SwimmingConfig,
+        &Actions<DefaultContext>,
+    )>,
+) {
+    for (mut character, mut mode, swimming, config, actions) in &mut query {
+        if *mode == MovementMode::Swimming
+            && swimming.treading
+            && actions.action::<Jump>().state() == ActionState::Fired
+        {
+            character.jump(config.jump_out_impulse);
+            *mode = MovementMode::Falling;
+        }
+    }
+}
+
+fn exit_swimming(trigger: Trigger<ExitMovementMode>, mut commands: Commands) {
+    if trigger.0 == MovementMode::Swimming {
+        commands.entity(trigger.target()).remove::<Swimming>();
+    }
+}
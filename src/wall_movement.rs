@@ -0,0 +1,407 @@
+use std::f32::consts::FRAC_PI_2;
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::{ActionState, Actions};
+
+use crate::{
+    camera::MainCamera,
+    character::*,
+    input::{self, DefaultContext, Jump},
+    move_and_slide::*,
+    movement::{Character, CharacterFilter, Frozen, acceleration, movement},
+    movement_mode::{DetectionSet, ExitMovementMode, MovementMode, MovementSet},
+};
+
+/// The [`MovementMode`] of a character running along a wall.
+pub const WALL_RUN_MODE: MovementMode = MovementMode::Custom("wall_run");
+
+/// Lets airborne characters run along walls beside them and jump off of them.
+pub struct WallMovementPlugin;
+
+impl Plugin for WallMovementPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_required_components::<Character, WallMovementConfig>();
+        app.add_systems(
+            FixedUpdate,
+            (
+                wall_run_update,
+                wall_run_detection.in_set(DetectionSet::WallRun),
+                tick_wall_run_cooldown,
+            )
+                .chain()
+                .after(movement)
+                .in_set(MovementSet::Update),
+        );
+        app.add_systems(Update, wall_jump_input);
+        app.add_observer(exit_wall_run);
+    }
+}
+
+/// Opt a collider out of wall running.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct NoWallRun;
+
+/// Configuration of wall running and wall jumping.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct WallMovementConfig {
+    /// How far to the side of the character to look for a wall.
+    pub attach_distance: f32,
+    /// How far a wall may be tilted from vertical to be runnable, in radians.
+    pub max_wall_tilt: f32,
+    /// The minimum horizontal speed needed to start and keep running along a wall.
+    pub min_speed: f32,
+    /// The maximum time the character can stay on a wall, in seconds.
+    pub max_duration: f32,
+    /// Multiplier applied to gravity while running along a wall.
+    pub gravity_scale: f32,
+    /// Impulse away from the wall when wall jumping.
+    pub jump_impulse: f32,
+    /// Impulse along `up` when wall jumping.
+    pub jump_up_impulse: f32,
+    /// Time after leaving a wall before the character can attach to a wall again, in seconds.
+    pub reattach_cooldown: f32,
+}
+
+impl Default for WallMovementConfig {
+    fn default() -> Self {
+        Self {
+            attach_distance: 0.2,
+            max_wall_tilt: 10f32.to_radians(),
+            min_speed: 3.0,
+            max_duration: 1.5,
+            gravity_scale: 0.25,
+            jump_impulse: 6.0,
+            jump_up_impulse: EXAMPLE_JUMP_IMPULSE,
+            reattach_cooldown: 0.3,
+        }
+    }
+}
+
+/// Inserted on a character while it's running along a wall.
+///
+/// The character is in [`WALL_RUN_MODE`] while this component is present.
+#[derive(Component, Reflect, Debug, Clone, Copy)]
+#[reflect(Component)]
+pub struct WallRunning {
+    /// The entity of the wall.
+    pub entity: Entity,
+    /// The normal of the wall, pointing towards the character.
+    pub normal: Dir3,
+    /// Time spent on the wall, in seconds.
+    pub elapsed: f32,
+}
+
+/// Prevents a character from attaching to a wall until the remaining time runs out.
+#[derive(Component, Reflect, Debug)]
+#[reflect(Component)]
+pub struct WallRunCooldown(pub f32);
+
+/// Sweep in the given `direction` and return the safe distance, entity and normal of a runnable wall.
+fn find_wall(
+    spatial_query: &SpatialQuery,
+    collider: &Collider,
+    translation: Vec3,
+    rotation: Quat,
+    up: Dir3,
+    direction: Dir3,
+    config: &WallMovementConfig,
+    epsilon: f32,
+    filter: &SpatialQueryFilter,
+    no_wall_run: &Query<(), With<NoWallRun>>,
+) -> Option<(f32, Entity, Dir3)> {
+    let (safe_distance, hit) = sweep_check(
+        collider,
+        epsilon,
+        translation,
+        direction,
+        config.attach_distance,
+        rotation,
+        spatial_query,
+        filter,
+    )?;
+
+    if no_wall_run.contains(hit.entity) {
+        return None;
+    }
+
+    let normal = Dir3::new(hit.normal1).ok()?;
+
+    // Only near-vertical walls are runnable
+    if (up.angle_between(*normal) - FRAC_PI_2).abs() > config.max_wall_tilt {
+        return None;
+    }
+
+    Some((safe_distance, hit.entity, normal))
+}
+
+fn wall_run_detection(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Character,
+            &mut MovementMode,
+            &Collider,
+            &CharacterFilter,
+            &WallMovementConfig,
+        ),
+        (Without<Frozen>, Without<WallRunCooldown>),
+    >,
+    no_wall_run: Query<(), With<NoWallRun>>,
+    spatial_query: SpatialQuery,
+) {
+    for (entity, transform, mut character, mut mode, collider, filter, config) in &mut query {
+        if *mode != MovementMode::Falling || character.grounded() {
+            continue;
+        }
+
+        let horizontal = character.velocity.reject_from_normalized(*character.up);
+        if horizontal.length() < config.min_speed {
+            continue;
+        }
+
+        let Ok(forward) = Dir3::new(horizontal) else {
+            continue;
+        };
+
+        let Ok(side) = Dir3::new(forward.cross(*character.up)) else {
+            continue;
+        };
+
+        // Look for a wall on either side of the character
+        let Some((_, wall, normal)) = [side, -side].into_iter().find_map(|direction| {
+            find_wall(
+                &spatial_query,
+                collider,
+                transform.translation,
+                transform.rotation,
+                character.up,
+                direction,
+                config,
+                character.config.epsilon,
+                &filter.0,
+                &no_wall_run,
+            )
+        }) else {
+            continue;
+        };
+
+        // Only attach when moving along the wall rather than into or away from it
+        if forward.dot(*normal).abs() > 0.5 {
+            continue;
+        }
+
+        // Keep the velocity along the wall and stop falling
+        let up = character.up;
+        character.velocity = character.velocity.reject_from_normalized(*normal);
+        let down = character.velocity.dot(*up).min(0.0);
+        character.velocity -= up * down;
+        *mode = WALL_RUN_MODE;
+
+        commands.entity(entity).insert(WallRunning {
+            entity: wall,
+            normal,
+            elapsed: 0.0,
+        });
+    }
+}
+
+fn wall_run_update(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &Actions<DefaultContext>,
+            &mut Transform,
+            &mut Character,
+            &mut MovementMode,
+            &mut WallRunning,
+            &mut PassingThrough,
+            &Collider,
+            &CharacterFilter,
+            &WallMovementConfig,
+        ),
+        Without<Frozen>,
+    >,
+    no_wall_run: Query<(), With<NoWallRun>>,
+    main_camera: Single<&Transform, (With<MainCamera>, Without<Character>)>,
+    one_way_platforms: Query<&GlobalTransform, With<OneWayPlatform>>,
+    time: Res<Time>,
+    spatial_query: SpatialQuery,
+) {
+    let main_camera_transform = main_camera.into_inner();
+    for (
+        entity,
+        actions,
+        mut transform,
+        mut character,
+        mut mode,
+        mut wall_run,
+        mut passing_through,
+        collider,
+        filter,
+        config,
+    ) in &mut query
+    {
+        if *mode != WALL_RUN_MODE {
+            continue;
+        }
+
+        wall_run.elapsed += time.delta_secs();
+
+        let input_vec = actions.action::<input::Move>().value().as_axis2d();
+        let camera_yaw = main_camera_transform.rotation.to_euler(EulerRot::YXZ).0;
+        let yaw_rotation = Quat::from_rotation_y(camera_yaw);
+        let direction = yaw_rotation * Vec3::new(input_vec.x, 0.0, -input_vec.y);
+
+        // Steering away from the wall lets go of it
+        if wall_run.elapsed > config.max_duration || direction.dot(*wall_run.normal) > 0.5 {
+            *mode = MovementMode::Falling;
+            commands
+                .entity(entity)
+                .insert(WallRunCooldown(config.reattach_cooldown));
+            continue;
+        }
+
+        // Make sure the wall is still there and stay attached to it
+        let Some((safe_distance, wall, normal)) = find_wall(
+            &spatial_query,
+            collider,
+            transform.translation,
+            transform.rotation,
+            character.up,
+            -wall_run.normal,
+            config,
+            character.config.epsilon,
+            &filter.0,
+            &no_wall_run,
+        ) else {
+            *mode = MovementMode::Falling;
+            continue;
+        };
+
+        transform.translation -= normal * safe_distance;
+        wall_run.entity = wall;
+        wall_run.normal = normal;
+
+        let up = character.up;
+
+        // Reduced gravity while on the wall
+        character.velocity = character.velocity.reject_from_normalized(*normal);
+        character.velocity += up * -EXAMPLE_GRAVITY * config.gravity_scale * time.delta_secs();
+
+        // Accelerate along the wall in the input direction
+        let wall_direction = direction
+            .reject_from_normalized(*normal)
+            .reject_from_normalized(*up);
+        let move_accel = acceleration(
+            character.velocity,
+            wall_direction,
+            EXAMPLE_AIR_ACCELERATION,
+            EXAMPLE_MOVEMENT_SPEED,
+            time.delta_secs(),
+        );
+        character.velocity += move_accel;
+
+        // Too slow to keep running
+        if character.velocity.reject_from_normalized(*up).length() < config.min_speed {
+            *mode = MovementMode::Falling;
+            commands
+                .entity(entity)
+                .insert(WallRunCooldown(config.reattach_cooldown));
+            continue;
+        }
+
+        let mut new_ground = None;
+
+        let move_result = move_and_slide(
+            &spatial_query,
+            collider,
+            transform.translation,
+            character.velocity,
+            transform.rotation,
+            character.config,
+            &filter.0,
+            time.delta_secs(),
+            |hit| {
+                if pass_through_one_way(hit, &one_way_platforms, &mut passing_through) {
+                    return false;
+                }
+
+                if let Some(ground) = Ground::new_if_walkable(
+                    hit.hit_data.entity,
+                    hit.hit_data.normal1,
+                    character.up,
+                    EXAMPLE_WALKABLE_ANGLE,
+                ) {
+                    new_ground = Some(ground);
+                }
+
+                character.velocity = character.velocity.reject_from(hit.hit_data.normal1);
+
+                true
+            },
+        );
+
+        transform.translation = move_result.new_translation;
+
+        // Landed on the ground, stop running along the wall
+        if new_ground.is_some() {
+            character.ground = new_ground;
+            *mode = MovementMode::Walking;
+        }
+    }
+}
+
+fn tick_wall_run_cooldown(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut WallRunCooldown)>,
+    time: Res<Time>,
+) {
+    for (entity, mut cooldown) in &mut query {
+        cooldown.0 -= time.delta_secs();
+
+        if cooldown.0 <= 0.0 {
+            commands.entity(entity).remove::<WallRunCooldown>();
+        }
+    }
+}
+
+fn wall_jump_input(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut Character,
+        &mut MovementMode,
+        &WallRunning,
+        &WallMovementConfig,
+        &Actions<DefaultContext>,
+    )>,
+) {
+    for (entity, mut character, mut mode, wall_run, config, actions) in &mut query {
+        if *mode != WALL_RUN_MODE || actions.action::<Jump>().state() != ActionState::Fired {
+            continue;
+        }
+
+        // Override downward velocity and launch away from the wall
+        let up = character.up;
+        let down = character.velocity.dot(*up).min(0.0);
+        character
+            .launch(wall_run.normal * config.jump_impulse + up * (config.jump_up_impulse - down));
+        *mode = MovementMode::Falling;
+
+        commands
+            .entity(entity)
+            .insert(WallRunCooldown(config.reattach_cooldown));
+    }
+}
+
+fn exit_wall_run(trigger: Trigger<ExitMovementMode>, mut commands: Commands) {
+    if trigger.0 == WALL_RUN_MODE {
+        commands.entity(trigger.target()).remove::<WallRunning>();
+    }
+}